@@ -1,4 +1,8 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::TokenAccount;
 
 declare_id!("MicroAiGovernance11111111111111111111111111");
 
@@ -15,23 +19,124 @@ pub mod governance {
     use super::*;
 
     /// Initialize the governance program with EPI threshold
-    pub fn initialize(
-        ctx: Context<Initialize>,
-        epi_threshold: u64,
-        voting_period: u64,
-        quorum_percentage: u64,
-    ) -> Result<()> {
+    pub fn initialize(ctx: Context<Initialize>, params: InitializeParams) -> Result<()> {
+        let InitializeParams {
+            epi_threshold,
+            voting_period,
+            quorum_bps,
+            execution_delay,
+            voting_mode,
+            proposal_deposit,
+            profit_weight,
+            ethics_weight,
+            abstain_counts_for_quorum,
+            max_title_len,
+            max_description_len,
+        } = params;
+        require!(
+            profit_weight.checked_add(ethics_weight) == Some(10000),
+            GovernanceError::InvalidEpiWeights
+        );
+        require!(
+            voting_mode <= VOTING_MODE_QUADRATIC,
+            GovernanceError::InvalidVotingMode
+        );
+        require!(
+            epi_threshold <= MAX_EPI_SCORE,
+            GovernanceError::InvalidScore
+        );
+        require!(quorum_bps <= 10000, GovernanceError::InvalidQuorum);
+        require!(
+            voting_period <= MAX_VOTING_PERIOD,
+            GovernanceError::InvalidVotingPeriod
+        );
+        require!(
+            max_title_len > 0 && max_title_len <= MAX_TITLE_LEN,
+            GovernanceError::InvalidTextLimit
+        );
+        require!(
+            max_description_len > 0 && max_description_len <= MAX_DESCRIPTION_LEN,
+            GovernanceError::InvalidTextLimit
+        );
+
         let governance = &mut ctx.accounts.governance;
         governance.authority = ctx.accounts.authority.key();
+        governance.pending_authority = None;
+        governance.authority_signers = [Pubkey::default(); MAX_AUTHORITY_SIGNERS];
+        governance.authority_threshold = 0;
         governance.epi_threshold = epi_threshold;
         governance.voting_period = voting_period;
-        governance.quorum_percentage = quorum_percentage;
+        governance.quorum_bps = quorum_bps;
+        governance.execution_delay = execution_delay;
+        governance.voting_mode = voting_mode;
+        governance.proposal_deposit = proposal_deposit;
+        governance.profit_weight = profit_weight;
+        governance.ethics_weight = ethics_weight;
+        governance.abstain_counts_for_quorum = abstain_counts_for_quorum;
         governance.proposal_count = 0;
+        governance.appeal_count = 0;
+        governance.epi_rejection_count = 0;
+        governance.finalize_sequence = 0;
+        governance.execution_sequence = 0;
+        governance.thought_count = 0;
         governance.total_voting_power = 0;
+        governance.total_quadratic_power = 0;
         governance.is_paused = false;
+        governance.submission_paused = false;
+        governance.max_execution_attempts = DEFAULT_MAX_EXECUTION_ATTEMPTS;
+        governance.max_options = MAX_PROPOSAL_OPTIONS;
+        governance.max_title_len = max_title_len;
+        governance.max_description_len = max_description_len;
+        governance.epi_override_threshold = 0;
+        governance.veto_decay_window = 0;
+        governance.veto_decay_per_window = 0;
+        governance.sortition_tiebreak = false;
+        governance.tie_breaks_for = false;
+        governance.allow_early_execution = false;
+        governance.total_credit_budget = 0;
+        governance.emergency_voting_period = 0;
+        governance.emergency_quorum = 0;
+        governance.execution_target_allowlist = Vec::new();
+        governance.min_margin = 0;
+        governance.min_voters = 0;
+        governance.min_document_schema_version = 0;
+        governance.category_active_counts = [0; MAX_CATEGORIES];
+        governance.max_active_per_category = 0;
+        governance.snapshot_staleness_slots = 0;
+        governance.execution_authority = None;
+        governance.base_veto_threshold = 1;
+        governance.guardian_count = 0;
+        governance.total_guardian_weight = 0;
+        governance.max_guardians = DEFAULT_MAX_GUARDIANS;
+        governance.guardian_term_length = 0;
+        governance.veto_threshold = 1;
+        governance.override_threshold = 0;
+        governance.category_execution_authorities = [None; MAX_CATEGORIES];
+        governance.category_quorums = [0; MAX_CATEGORIES];
+        governance.min_proposal_power = 0;
+        governance.min_vote_power = 0;
+        governance.max_start_delay = 0;
+        governance.proposal_cooldown = 0;
+        governance.cleanup_delay = 0;
+        governance.proposal_gate_mint = None;
+        governance.recent_epi_scores = [0; EPI_HISTORY_LEN];
+        governance.recent_epi_index = 0;
+        governance.veto_ethics_threshold = 0;
+        governance.veto_window = 0;
+        governance.veto_cooldown = 0;
+        governance.extension_window = 0;
+        governance.extension_period = 0;
+        governance.max_extensions = 0;
+        governance.dimension_weights = [0; MAX_EPI_DIMENSIONS];
+        governance.dimension_floors = [0; MAX_EPI_DIMENSIONS];
         governance.bump = ctx.bumps.governance;
+        ctx.accounts.deposit_vault.bump = ctx.bumps.deposit_vault;
+        ctx.accounts.treasury.bump = ctx.bumps.treasury;
 
-        msg!("Governance initialized with EPI threshold: {}", epi_threshold);
+        msg!(
+            "Governance initialized with EPI threshold: {}",
+            epi_threshold
+        );
         emit!(GovernanceInitialized {
             authority: governance.authority,
             epi_threshold,
@@ -43,580 +148,7703 @@ pub mod governance {
     }
 
     /// Submit a proposal with EPI validation
-    pub fn submit_proposal(
-        ctx: Context<SubmitProposal>,
-        title: String,
-        description: String,
-        epi_score: u64,
-        profit_score: u64,
-        ethics_score: u64,
-        ipfs_hash: [u8; 32],
-        thought_hash: [u8; 32],
-    ) -> Result<()> {
+    ///
+    /// Voting opens `start_delay` slots after submission (at most
+    /// `max_start_delay`) and runs for `voting_period` slots from there.
+    pub fn submit_proposal(ctx: Context<SubmitProposal>, params: ProposalParams) -> Result<()> {
+        let ProposalParams {
+            title,
+            description,
+            profit_score,
+            ethics_score,
+            ipfs_hash,
+            thought_hash,
+            extra_sub_scores,
+            category,
+            num_options,
+            ranked,
+            execution_condition,
+            execution_target,
+            instruction_data,
+            document_schema_version,
+            min_epi_override,
+            start_delay,
+            supersedes,
+            recipient,
+            amount,
+        } = params;
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        require!(
+            !ctx.accounts.governance.submission_paused,
+            GovernanceError::SubmissionsPaused
+        );
+        require!(
+            title.len() <= ctx.accounts.governance.max_title_len as usize,
+            GovernanceError::TitleTooLong
+        );
+        require!(
+            description.len() <= ctx.accounts.governance.max_description_len as usize,
+            GovernanceError::DescriptionTooLong
+        );
+        require!(
+            document_schema_version >= ctx.accounts.governance.min_document_schema_version,
+            GovernanceError::DocumentSchemaTooOld
+        );
+        require!(
+            start_delay <= ctx.accounts.governance.max_start_delay,
+            GovernanceError::StartDelayTooLong
+        );
+
+        require_category_capacity(&ctx.accounts.governance, category)?;
+        require!(num_options >= 2, GovernanceError::InvalidOptionCount);
+        require!(
+            num_options <= ctx.accounts.governance.max_options,
+            GovernanceError::TooManyOptions
+        );
+        // With two options the first round already decides; ranking adds nothing
+        require!(
+            !ranked || num_options > 2,
+            GovernanceError::InvalidOptionCount
+        );
+        if let Some(condition) = &execution_condition {
+            require!(
+                condition.operator <= CONDITION_EQ,
+                GovernanceError::InvalidConditionOperator
+            );
+        }
+        if let Some(target) = &execution_target {
+            require!(
+                ctx.accounts.governance.is_execution_target_allowed(target),
+                GovernanceError::ExecutionTargetNotAllowed
+            );
+        }
+        require!(
+            instruction_data.len() <= MAX_INSTRUCTION_DATA_LEN,
+            GovernanceError::InstructionDataTooLong
+        );
+        require!(
+            execution_target.is_some() || instruction_data.is_empty(),
+            GovernanceError::MissingExecutionTarget
+        );
         require!(
-            epi_score >= ctx.accounts.governance.epi_threshold,
+            recipient.is_some() || amount == 0,
+            GovernanceError::InvalidDisbursement
+        );
+
+        // A proposal may raise its own EPI bar but never lower it below the
+        // global threshold
+        let epi_threshold = match min_epi_override {
+            Some(threshold) => {
+                require!(
+                    threshold >= ctx.accounts.governance.epi_threshold,
+                    GovernanceError::EPIBelowThreshold
+                );
+                require!(threshold <= MAX_EPI_SCORE, GovernanceError::InvalidScore);
+                threshold
+            }
+            None => ctx.accounts.governance.epi_threshold,
+        };
+
+        let EpiEvaluation {
+            epi_score,
+            sub_scores,
+            composite: composite_epi,
+            meets_threshold,
+        } = evaluate_epi(
+            &ctx.accounts.governance,
+            profit_score,
+            ethics_score,
+            &extra_sub_scores,
+            epi_threshold,
+        )?;
+
+        // Below-threshold proposals are rejected unless guardian overrides are
+        // enabled, in which case they wait in `PendingEpiOverride`.
+        require!(
+            meets_threshold || ctx.accounts.governance.epi_override_threshold > 0,
             GovernanceError::EPIBelowThreshold
         );
-        require!(title.len() <= 64, GovernanceError::TitleTooLong);
-        require!(description.len() <= 256, GovernanceError::DescriptionTooLong);
-        require!(profit_score <= 1_000_000, GovernanceError::InvalidScore);
-        require!(ethics_score <= 1_000_000, GovernanceError::InvalidScore);
+
+        let min_proposal_power = ctx.accounts.governance.min_proposal_power;
+        if min_proposal_power > 0 {
+            let proposer_power = ctx
+                .accounts
+                .proposer_account
+                .as_ref()
+                .map_or(0, |account| account.voting_power);
+            require!(
+                proposer_power >= min_proposal_power,
+                GovernanceError::InsufficientProposalPower
+            );
+        }
+
+        if let Some(gate_mint) = ctx.accounts.governance.proposal_gate_mint {
+            let token_account = ctx
+                .accounts
+                .proposer_token_account
+                .as_ref()
+                .ok_or(GovernanceError::NotEligibleToPropose)?;
+            require!(
+                token_account.mint == gate_mint
+                    && token_account.owner == ctx.accounts.proposer.key()
+                    && token_account.amount > 0,
+                GovernanceError::NotEligibleToPropose
+            );
+        }
+
+        let proposal_cooldown = ctx.accounts.governance.proposal_cooldown;
+        let submitted_slot = Clock::get()?.slot;
+        match ctx.accounts.proposer_account.as_mut() {
+            Some(proposer_account) => {
+                if proposer_account.proposals_submitted > 0 {
+                    let next_allowed = proposer_account
+                        .last_proposal_slot
+                        .checked_add(proposal_cooldown)
+                        .ok_or(GovernanceError::SlotOverflow)?;
+                    require!(
+                        submitted_slot >= next_allowed,
+                        GovernanceError::ProposalCooldownActive
+                    );
+                }
+                proposer_account.last_proposal_slot = submitted_slot;
+                proposer_account.proposals_submitted = proposer_account
+                    .proposals_submitted
+                    .checked_add(1)
+                    .ok_or(GovernanceError::ArithmeticOverflow)?;
+            }
+            // Without a voter account there is nowhere to track the cooldown
+            None => require!(proposal_cooldown == 0, GovernanceError::InvalidVoterAccount),
+        }
+
+        let proposal_deposit = ctx.accounts.governance.proposal_deposit;
+        if proposal_deposit > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.proposer.to_account_info(),
+                        to: ctx.accounts.deposit_vault.to_account_info(),
+                    },
+                ),
+                proposal_deposit,
+            )?;
+        }
+
+        register_thought_hash(
+            &ctx.accounts.thought_hash_registry,
+            ctx.bumps.thought_hash_registry,
+            &thought_hash,
+            ctx.accounts.governance.proposal_count,
+            &ctx.accounts.proposer,
+            &ctx.accounts.system_program,
+        )?;
 
         let governance = &mut ctx.accounts.governance;
         let proposal = &mut ctx.accounts.proposal;
         let clock = Clock::get()?;
 
-        proposal.id = governance.proposal_count;
-        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.init_from(
+            governance,
+            ctx.accounts.proposer.key(),
+            &clock,
+            ctx.bumps.proposal,
+        )?;
+        proposal.deposit = proposal_deposit;
         proposal.title = title.clone();
         proposal.description = description;
         proposal.epi_score = epi_score;
         proposal.profit_score = profit_score;
         proposal.ethics_score = ethics_score;
+        proposal.sub_scores = sub_scores;
+        proposal.composite_epi = composite_epi.unwrap_or(0);
+        proposal.epi_threshold = epi_threshold;
         proposal.ipfs_hash = ipfs_hash;
+        proposal.document_schema_version = document_schema_version;
         proposal.thought_hash = thought_hash;
-        proposal.votes_for = 0;
-        proposal.votes_against = 0;
-        proposal.votes_abstain = 0;
-        proposal.start_slot = clock.slot;
-        proposal.end_slot = clock.slot + governance.voting_period;
-        proposal.status = ProposalStatus::Active;
-        proposal.created_at = clock.unix_timestamp;
-        proposal.executed_at = 0;
-        proposal.bump = ctx.bumps.proposal;
+        if num_options > 2 {
+            proposal.option_votes = vec![0; num_options as usize];
+        }
+        let start_slot = clock
+            .slot
+            .checked_add(start_delay)
+            .ok_or(GovernanceError::SlotOverflow)?;
+        proposal.schedule_voting(start_slot, governance.voting_period, &clock)?;
+        if !meets_threshold {
+            proposal.status = ProposalStatus::PendingEpiOverride;
+        }
+        proposal.category = category;
+        proposal.num_options = num_options;
+        proposal.execution_condition = execution_condition;
+        proposal.execution_target = execution_target;
+        proposal.instruction_data = instruction_data;
+        proposal.is_ranked = ranked;
+        proposal.supersedes = supersedes;
+        proposal.recipient = recipient;
+        proposal.amount = amount;
 
+        governance.record_epi_score(epi_score);
         governance.proposal_count += 1;
+        governance.category_active_counts[category as usize] += 1;
+
+        if let Some(superseded_id) = supersedes {
+            let superseded = ctx
+                .accounts
+                .superseded_proposal
+                .as_mut()
+                .ok_or(GovernanceError::InvalidProposal)?;
+            require_keys_eq!(
+                superseded.key(),
+                Proposal::address(superseded_id),
+                GovernanceError::InvalidProposal
+            );
+            // Only the proposer's own still-active version is retired; any
+            // other referenced proposal is just linked
+            let cancelled = superseded.status == ProposalStatus::Active
+                && superseded.proposer == proposal.proposer;
+            if cancelled {
+                superseded.status = ProposalStatus::Cancelled;
+                superseded.cancel_reason = Some(CancelReason::Superseded);
+                governance.release_active_slot(superseded);
+            }
+
+            emit!(ProposalSuperseded {
+                proposal_id: superseded_id,
+                superseded_by: proposal.id,
+                cancelled,
+                timestamp: clock.unix_timestamp,
+            });
+        }
 
         emit!(ProposalSubmitted {
             proposal_id: proposal.id,
             proposer: proposal.proposer,
             title,
             epi_score,
+            category,
             start_slot: proposal.start_slot,
             end_slot: proposal.end_slot,
+            end_ts: proposal.end_ts,
             timestamp: proposal.created_at,
         });
 
-        msg!("Proposal {} submitted with EPI score: {}", proposal.id, epi_score);
+        msg!(
+            "Proposal {} submitted with EPI score: {}",
+            proposal.id,
+            epi_score
+        );
         Ok(())
     }
 
-    /// Cast a vote on a proposal
-    pub fn vote(
-        ctx: Context<Vote>,
-        proposal_id: u64,
-        support: u8,
-        reason: String,
+    /// Submit an emergency proposal as an active guardian
+    ///
+    /// Voting opens immediately and lasts `emergency_voting_period` slots, and
+    /// the proposal needs `emergency_quorum` rather than the category quorum.
+    /// There is no EPI gate or deposit; otherwise it is finalized and executed
+    /// like any other proposal.
+    pub fn submit_emergency_proposal(
+        ctx: Context<SubmitEmergencyProposal>,
+        title: String,
+        description: String,
+        ipfs_hash: [u8; 32],
+        category: u8,
+        execution_target: Option<Pubkey>,
+        instruction_data: Vec<u8>,
     ) -> Result<()> {
-        let proposal = &mut ctx.accounts.proposal;
-        let vote_record = &mut ctx.accounts.vote_record;
-        let voter_account = &ctx.accounts.voter_account;
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        require!(
+            !ctx.accounts.governance.submission_paused,
+            GovernanceError::SubmissionsPaused
+        );
         let clock = Clock::get()?;
-
+        ctx.accounts
+            .guardian_account
+            .require_serving(clock.unix_timestamp)?;
         require!(
-            proposal.status == ProposalStatus::Active,
-            GovernanceError::ProposalNotActive
+            ctx.accounts.governance.emergency_voting_period > 0,
+            GovernanceError::EmergencyProposalsDisabled
+        );
+        require!(
+            title.len() <= ctx.accounts.governance.max_title_len as usize,
+            GovernanceError::TitleTooLong
+        );
+        require!(
+            description.len() <= ctx.accounts.governance.max_description_len as usize,
+            GovernanceError::DescriptionTooLong
+        );
+        require!(
+            (category as usize) < MAX_CATEGORIES,
+            GovernanceError::InvalidCategory
+        );
+        if let Some(target) = &execution_target {
+            require!(
+                ctx.accounts.governance.is_execution_target_allowed(target),
+                GovernanceError::ExecutionTargetNotAllowed
+            );
+        }
+        require!(
+            instruction_data.len() <= MAX_INSTRUCTION_DATA_LEN,
+            GovernanceError::InstructionDataTooLong
+        );
+        require!(
+            execution_target.is_some() || instruction_data.is_empty(),
+            GovernanceError::MissingExecutionTarget
         );
-        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
-        require!(clock.slot >= proposal.start_slot, GovernanceError::VotingNotStarted);
-        require!(clock.slot <= proposal.end_slot, GovernanceError::VotingEnded);
-        require!(support <= 2, GovernanceError::InvalidVoteType);
 
-        let voting_power = voter_account.voting_power;
-        require!(voting_power > 0, GovernanceError::NoVotingPower);
+        let governance = &mut ctx.accounts.governance;
+        let proposal = &mut ctx.accounts.proposal;
 
-        vote_record.voter = ctx.accounts.voter.key();
-        vote_record.proposal_id = proposal_id;
-        vote_record.support = support;
-        vote_record.voting_power = voting_power;
-        vote_record.timestamp = clock.unix_timestamp;
-        vote_record.bump = ctx.bumps.vote_record;
+        proposal.init_from(
+            governance,
+            ctx.accounts.proposer.key(),
+            &clock,
+            ctx.bumps.proposal,
+        )?;
+        proposal.title = title.clone();
+        proposal.description = description;
+        proposal.ipfs_hash = ipfs_hash;
+        proposal.schedule_voting(clock.slot, governance.emergency_voting_period, &clock)?;
+        proposal.category = category;
+        proposal.execution_target = execution_target;
+        proposal.instruction_data = instruction_data;
+        proposal.is_emergency = true;
 
-        match support {
-            0 => proposal.votes_against += voting_power,
-            1 => proposal.votes_for += voting_power,
-            2 => proposal.votes_abstain += voting_power,
-            _ => return Err(GovernanceError::InvalidVoteType.into()),
-        }
+        governance.proposal_count += 1;
+        governance.category_active_counts[category as usize] += 1;
 
-        emit!(VoteCast {
-            proposal_id,
-            voter: ctx.accounts.voter.key(),
-            support,
-            voting_power,
-            reason,
-            timestamp: clock.unix_timestamp,
+        emit!(EmergencyProposalSubmitted {
+            proposal_id: proposal.id,
+            guardian: proposal.proposer,
+            title,
+            category,
+            end_slot: proposal.end_slot,
+            end_ts: proposal.end_ts,
+            quorum_bps: governance.emergency_quorum,
+            timestamp: proposal.created_at,
         });
 
-        msg!("Vote cast on proposal {}: support={} power={}", proposal_id, support, voting_power);
+        msg!("Emergency proposal {} submitted by guardian", proposal.id);
         Ok(())
     }
 
-    /// Execute a proposal if it has passed
-    pub fn execute_proposal(ctx: Context<ExecuteProposal>, proposal_id: u64) -> Result<()> {
+    /// Submit a ratification vote for an emergency action a guardian already took
+    ///
+    /// The proposal votes normally but is never executed; `finalize_ratification`
+    /// records the DAO's approval or disapproval of the action.
+    pub fn submit_ratification(
+        ctx: Context<SubmitRatification>,
+        title: String,
+        action_description: String,
+        executed_at_slot: u64,
+        ipfs_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts
+            .guardian_account
+            .require_serving(Clock::get()?.unix_timestamp)?;
+        require!(
+            title.len() <= ctx.accounts.governance.max_title_len as usize,
+            GovernanceError::TitleTooLong
+        );
+        require!(
+            action_description.len() <= ctx.accounts.governance.max_description_len as usize,
+            GovernanceError::DescriptionTooLong
+        );
+
+        let governance = &mut ctx.accounts.governance;
         let proposal = &mut ctx.accounts.proposal;
-        let governance = &ctx.accounts.governance;
         let clock = Clock::get()?;
 
-        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
-        require!(clock.slot > proposal.end_slot, GovernanceError::VotingNotEnded);
         require!(
-            proposal.status == ProposalStatus::Active,
-            GovernanceError::ProposalNotActive
+            executed_at_slot <= clock.slot,
+            GovernanceError::InvalidRatificationSlot
         );
 
-        let total_votes = proposal.votes_for + proposal.votes_against + proposal.votes_abstain;
-        let quorum_votes = (governance.total_voting_power * governance.quorum_percentage) / 10000;
-        require!(total_votes >= quorum_votes, GovernanceError::QuorumNotReached);
-        require!(proposal.votes_for > proposal.votes_against, GovernanceError::ProposalNotPassed);
+        proposal.init_from(
+            governance,
+            ctx.accounts.proposer.key(),
+            &clock,
+            ctx.bumps.proposal,
+        )?;
+        proposal.title = title.clone();
+        proposal.description = action_description;
+        proposal.ipfs_hash = ipfs_hash;
+        proposal.is_ratification = true;
+        proposal.ratified_action_slot = executed_at_slot;
 
-        proposal.status = ProposalStatus::Executed;
-        proposal.executed_at = clock.unix_timestamp;
+        governance.proposal_count += 1;
 
-        emit!(ProposalExecuted {
-            proposal_id,
-            executor: ctx.accounts.executor.key(),
-            votes_for: proposal.votes_for,
-            votes_against: proposal.votes_against,
-            timestamp: clock.unix_timestamp,
+        emit!(RatificationSubmitted {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer,
+            title,
+            executed_at_slot,
+            end_slot: proposal.end_slot,
+            timestamp: proposal.created_at,
         });
 
-        msg!("Proposal {} executed", proposal_id);
+        msg!(
+            "Ratification {} submitted for action at slot {}",
+            proposal.id,
+            executed_at_slot
+        );
         Ok(())
     }
 
-    /// Guardian veto power (Class A stakeholders)
-    pub fn veto_proposal(
-        ctx: Context<VetoProposal>,
+    /// Record the DAO's verdict on a ratification once voting has ended
+    pub fn finalize_ratification(
+        ctx: Context<FinalizeRatification>,
         proposal_id: u64,
-        reason: String,
     ) -> Result<()> {
         let proposal = &mut ctx.accounts.proposal;
-        let guardian = &mut ctx.accounts.guardian_account;
+        let governance = &ctx.accounts.governance;
         let clock = Clock::get()?;
 
         require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
-        require!(guardian.is_active, GovernanceError::NotGuardian);
+        require!(proposal.is_ratification, GovernanceError::NotRatification);
         require!(
-            proposal.status == ProposalStatus::Active ||
-            proposal.status == ProposalStatus::Succeeded,
-            GovernanceError::CannotVeto
+            !is_voting_open(proposal, clock.slot),
+            GovernanceError::VotingNotEnded
+        );
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
         );
 
-        proposal.status = ProposalStatus::Vetoed;
-        guardian.veto_count += 1;
+        let defeat_reason = evaluate_outcome(proposal, governance)?;
+        let approved = defeat_reason.is_none();
 
-        emit!(ProposalVetoed {
+        proposal.ratification_result = Some(approved);
+        proposal.defeat_reason = defeat_reason;
+        proposal.status = if approved {
+            ProposalStatus::Succeeded
+        } else {
+            ProposalStatus::Defeated
+        };
+
+        emit!(RatificationFinalized {
             proposal_id,
-            guardian: ctx.accounts.guardian.key(),
-            reason,
+            approved,
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
             timestamp: clock.unix_timestamp,
         });
 
-        msg!("Proposal {} vetoed by guardian", proposal_id);
+        msg!(
+            "Ratification {} finalized: approved={}",
+            proposal_id,
+            approved
+        );
         Ok(())
     }
 
-    /// Log AI thought process
-    pub fn log_thought(
-        ctx: Context<LogThought>,
-        agent_id: String,
-        action: String,
-        epi_score: u64,
-        reasoning_hash: [u8; 32],
-        inputs_hash: [u8; 32],
-        outputs_hash: [u8; 32],
-    ) -> Result<()> {
-        let thought_log = &mut ctx.accounts.thought_log;
+    /// Guardian approval to let a below-threshold proposal into voting
+    ///
+    /// Once `governance.epi_override_threshold` distinct guardians approve, the
+    /// proposal becomes `Active` and its voting window starts from that slot.
+    pub fn approve_epi_override(ctx: Context<ApproveEpiOverride>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &ctx.accounts.governance;
+        let approval = &mut ctx.accounts.override_approval;
         let clock = Clock::get()?;
 
-        require!(agent_id.len() <= 32, GovernanceError::AgentIdTooLong);
-        require!(action.len() <= 64, GovernanceError::ActionTooLong);
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        ctx.accounts
+            .guardian_account
+            .require_serving(clock.unix_timestamp)?;
+        require!(
+            proposal.status == ProposalStatus::PendingEpiOverride,
+            GovernanceError::NotPendingEpiOverride
+        );
 
-        thought_log.agent_id = agent_id.clone();
-        thought_log.action = action;
-        thought_log.epi_score = epi_score;
-        thought_log.reasoning_hash = reasoning_hash;
-        thought_log.inputs_hash = inputs_hash;
-        thought_log.outputs_hash = outputs_hash;
-        thought_log.timestamp = clock.unix_timestamp;
-        thought_log.logger = ctx.accounts.logger.key();
-        thought_log.bump = ctx.bumps.thought_log;
+        approval.guardian = ctx.accounts.guardian.key();
+        approval.proposal_id = proposal_id;
+        approval.timestamp = clock.unix_timestamp;
+        approval.bump = ctx.bumps.override_approval;
 
-        emit!(ThoughtLogged {
-            proposal_id: 0,
-            agent_id,
-            thought_hash: reasoning_hash,
-            timestamp: thought_log.timestamp,
+        proposal.epi_override_approvals += 1;
+        let activated = proposal.epi_override_approvals >= governance.epi_override_threshold;
+        if activated {
+            proposal.status = ProposalStatus::Active;
+            proposal.start_slot = clock.slot;
+            proposal.end_slot = voting_end_slot(clock.slot, governance.voting_period)?;
+            proposal.start_ts = slot_timestamp(&clock, proposal.start_slot);
+            proposal.end_ts = slot_timestamp(&clock, proposal.end_slot);
+            proposal.total_power_snapshot = governance.effective_total_power();
+            proposal.snapshot_slot = clock.slot;
+        }
+
+        emit!(EpiOverrideApproved {
+            proposal_id,
+            guardian: approval.guardian,
+            approvals: proposal.epi_override_approvals,
+            threshold: governance.epi_override_threshold,
+            activated,
+            timestamp: clock.unix_timestamp,
         });
 
-        msg!("Thought logged for agent: {}", thought_log.agent_id);
+        msg!(
+            "EPI override approval {}/{} on proposal {}",
+            proposal.epi_override_approvals,
+            governance.epi_override_threshold,
+            proposal_id
+        );
         Ok(())
     }
 
-    /// Register a voter with voting power
-    pub fn register_voter(ctx: Context<RegisterVoter>, voting_power: u64) -> Result<()> {
-        let voter_account = &mut ctx.accounts.voter_account;
+    /// Record a proposal that fails the EPI gate
+    ///
+    /// A rejected `submit_proposal` reverts without a trace, so clients that
+    /// want gate analytics call this with the same scores instead. The scores
+    /// must actually fall below the global threshold.
+    pub fn record_epi_rejection(
+        ctx: Context<RecordEpiRejection>,
+        profit_score: u64,
+        ethics_score: u64,
+        extra_sub_scores: Vec<SubScore>,
+    ) -> Result<()> {
         let governance = &mut ctx.accounts.governance;
+        let epi_threshold = governance.epi_threshold;
+        let EpiEvaluation {
+            epi_score,
+            meets_threshold,
+            ..
+        } = evaluate_epi(
+            governance,
+            profit_score,
+            ethics_score,
+            &extra_sub_scores,
+            epi_threshold,
+        )?;
+        require!(!meets_threshold, GovernanceError::EPIMeetsThreshold);
 
-        voter_account.voter = ctx.accounts.voter.key();
-        voter_account.voting_power = voting_power;
-        voter_account.registered_at = Clock::get()?.unix_timestamp;
-        voter_account.bump = ctx.bumps.voter_account;
+        let log = &mut ctx.accounts.rejection_log;
+        let clock = Clock::get()?;
+        log.rejection_id = governance.epi_rejection_count;
+        log.proposer = ctx.accounts.proposer.key();
+        log.epi_score = epi_score;
+        log.epi_threshold = epi_threshold;
+        log.timestamp = clock.unix_timestamp;
+        log.bump = ctx.bumps.rejection_log;
 
-        governance.total_voting_power += voting_power;
+        governance.epi_rejection_count += 1;
 
-        emit!(VoterRegistered {
-            voter: ctx.accounts.voter.key(),
-            voting_power,
-            timestamp: voter_account.registered_at,
+        emit!(EPIRejection {
+            rejection_id: log.rejection_id,
+            proposer: log.proposer,
+            epi_score,
+            epi_threshold,
+            timestamp: clock.unix_timestamp,
         });
 
-        msg!("Voter registered with power: {}", voting_power);
+        msg!(
+            "EPI rejection recorded: score {} below {}",
+            epi_score,
+            epi_threshold
+        );
         Ok(())
     }
 
-    /// Add a guardian
-    pub fn add_guardian(ctx: Context<AddGuardian>) -> Result<()> {
-        let guardian_account = &mut ctx.accounts.guardian_account;
+    /// Appeal an EPI rejection with revised scores
+    ///
+    /// For proposals that fail the EPI gate on stale inputs: the proposer
+    /// files the proposal with updated `profit_score` / `ethics_score` and a
+    /// hash of the supporting evidence. Nothing is created until a guardian
+    /// approves the appeal with `resolve_appeal`.
+    pub fn appeal_epi(ctx: Context<AppealEpi>, params: AppealParams) -> Result<()> {
+        let AppealParams {
+            title,
+            description,
+            profit_score,
+            ethics_score,
+            evidence_hash,
+            ipfs_hash,
+            thought_hash,
+            category,
+        } = params;
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        require!(
+            !ctx.accounts.governance.submission_paused,
+            GovernanceError::SubmissionsPaused
+        );
+        require!(
+            title.len() <= ctx.accounts.governance.max_title_len as usize,
+            GovernanceError::TitleTooLong
+        );
+        require!(
+            description.len() <= ctx.accounts.governance.max_description_len as usize,
+            GovernanceError::DescriptionTooLong
+        );
+        require!(
+            (category as usize) < MAX_CATEGORIES,
+            GovernanceError::InvalidCategory
+        );
+
+        let governance = &mut ctx.accounts.governance;
+        let EpiEvaluation { epi_score, .. } = evaluate_epi(
+            governance,
+            profit_score,
+            ethics_score,
+            &[],
+            governance.epi_threshold,
+        )?;
+
+        let appeal = &mut ctx.accounts.appeal;
         let clock = Clock::get()?;
+        appeal.appeal_id = governance.appeal_count;
+        appeal.proposer = ctx.accounts.proposer.key();
+        appeal.title = title;
+        appeal.description = description;
+        appeal.profit_score = profit_score;
+        appeal.ethics_score = ethics_score;
+        appeal.epi_score = epi_score;
+        appeal.evidence_hash = evidence_hash;
+        appeal.ipfs_hash = ipfs_hash;
+        appeal.thought_hash = thought_hash;
+        appeal.category = category;
+        appeal.resolved_by = None;
+        appeal.proposal_id = None;
+        appeal.created_at = clock.unix_timestamp;
+        appeal.bump = ctx.bumps.appeal;
 
-        guardian_account.guardian = ctx.accounts.new_guardian.key();
-        guardian_account.is_active = true;
-        guardian_account.veto_count = 0;
-        guardian_account.added_at = clock.unix_timestamp;
-        guardian_account.bump = ctx.bumps.guardian_account;
+        governance.appeal_count += 1;
 
-        emit!(GuardianAdded {
-            guardian: ctx.accounts.new_guardian.key(),
+        emit!(EpiAppealSubmitted {
+            appeal_id: appeal.appeal_id,
+            proposer: appeal.proposer,
+            profit_score,
+            ethics_score,
+            epi_score,
+            evidence_hash,
             timestamp: clock.unix_timestamp,
         });
 
-        msg!("Guardian added: {}", ctx.accounts.new_guardian.key());
+        msg!(
+            "EPI appeal {} submitted with revised score: {}",
+            appeal.appeal_id,
+            epi_score
+        );
         Ok(())
     }
 
-    /// Update EPI threshold
-    pub fn update_epi_threshold(ctx: Context<UpdateGovernance>, new_threshold: u64) -> Result<()> {
-        let governance = &mut ctx.accounts.governance;
+    /// Approve a pending EPI appeal, creating its proposal with the revised score
+    ///
+    /// The approving guardian is recorded on the appeal and pays for the new
+    /// accounts. The proposal opens for voting immediately regardless of the
+    /// EPI threshold, carries no deposit and has no execution payload.
+    pub fn resolve_appeal(ctx: Context<ResolveAppeal>, appeal_id: u64) -> Result<()> {
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        ctx.accounts
+            .guardian_account
+            .require_serving(Clock::get()?.unix_timestamp)?;
+        let appeal = &ctx.accounts.appeal;
+        require!(
+            appeal.appeal_id == appeal_id,
+            GovernanceError::InvalidAppeal
+        );
+        require!(
+            appeal.proposal_id.is_none(),
+            GovernanceError::AppealAlreadyResolved
+        );
+
+        let category = appeal.category;
+        let max_active = ctx.accounts.governance.max_active_per_category;
+        require!(
+            max_active == 0
+                || ctx.accounts.governance.category_active_counts[category as usize] < max_active,
+            GovernanceError::CategoryActiveLimitReached
+        );
+
+        let thought_hash = appeal.thought_hash;
+        register_thought_hash(
+            &ctx.accounts.thought_hash_registry,
+            ctx.bumps.thought_hash_registry,
+            &thought_hash,
+            ctx.accounts.governance.proposal_count,
+            &ctx.accounts.guardian,
+            &ctx.accounts.system_program,
+        )?;
+        let sub_scores = build_sub_scores(appeal.profit_score, appeal.ethics_score, &[])?;
+
+        let governance = &mut ctx.accounts.governance;
+        let appeal = &mut ctx.accounts.appeal;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        proposal.init_from(governance, appeal.proposer, &clock, ctx.bumps.proposal)?;
+        proposal.title = appeal.title.clone();
+        proposal.description = appeal.description.clone();
+        proposal.epi_score = appeal.epi_score;
+        proposal.profit_score = appeal.profit_score;
+        proposal.ethics_score = appeal.ethics_score;
+        proposal.sub_scores = sub_scores;
+        proposal.ipfs_hash = appeal.ipfs_hash;
+        proposal.document_schema_version = governance.min_document_schema_version;
+        proposal.thought_hash = thought_hash;
+        proposal.category = category;
+
+        appeal.resolved_by = Some(ctx.accounts.guardian.key());
+        appeal.proposal_id = Some(proposal.id);
+
+        governance.record_epi_score(appeal.epi_score);
+        governance.proposal_count += 1;
+        governance.category_active_counts[category as usize] += 1;
+
+        emit!(EpiAppealResolved {
+            appeal_id,
+            proposal_id: proposal.id,
+            guardian: ctx.accounts.guardian.key(),
+            epi_score: appeal.epi_score,
+            timestamp: clock.unix_timestamp,
+        });
+        emit!(ProposalSubmitted {
+            proposal_id: proposal.id,
+            proposer: proposal.proposer,
+            title: proposal.title.clone(),
+            epi_score: proposal.epi_score,
+            category,
+            start_slot: proposal.start_slot,
+            end_slot: proposal.end_slot,
+            end_ts: proposal.end_ts,
+            timestamp: proposal.created_at,
+        });
+
+        msg!(
+            "EPI appeal {} approved as proposal {}",
+            appeal_id,
+            proposal.id
+        );
+        Ok(())
+    }
+
+    /// Cast a vote on a proposal
+    ///
+    /// `support` on a binary proposal: 0 = against, 1 = for, 2 = abstain.
+    /// On a multi-option proposal it is the option index, and `num_options`
+    /// abstains. Events report it decoded as a `VoteSupport`.
+    pub fn vote(ctx: Context<Vote>, proposal_id: u64, support: u8, reason: String) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(
+            !proposal.is_ranked || support == proposal.abstain_option(),
+            GovernanceError::RankedBallotRequired
+        );
+        cast_ballot(ctx, proposal_id, support, Vec::new(), reason)
+    }
+
+    /// Cast a ranked ballot on a ranked-choice proposal
+    ///
+    /// `rankings` lists distinct option indices, most preferred first; options
+    /// left out are never counted for this ballot. It counts for its first
+    /// choice until `finalize_round` eliminates that option.
+    pub fn vote_ranked(
+        ctx: Context<Vote>,
+        proposal_id: u64,
+        rankings: Vec<u8>,
+        reason: String,
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.is_ranked, GovernanceError::NotRankedChoice);
+        require_valid_rankings(proposal, &rankings)?;
+        let first_choice = rankings[0];
+        cast_ballot(ctx, proposal_id, first_choice, rankings, reason)
+    }
+
+    /// Move an existing vote to a different option while voting is still open
+    ///
+    /// The power recorded on the vote is moved between tally buckets; the
+    /// voter's participation count is unchanged.
+    pub fn change_vote(
+        ctx: Context<ChangeVote>,
+        proposal_id: u64,
+        new_support: u8,
+        reason: String,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let voter_account = &mut ctx.accounts.voter_account;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            is_voting_open(proposal, clock.slot),
+            GovernanceError::VotingEnded
+        );
+        require!(!proposal.is_frozen, GovernanceError::ProposalFrozen);
+        require!(
+            !ctx.accounts.governance.allow_early_execution,
+            GovernanceError::VotesFinal
+        );
+        // A ranked ballot is withdrawn and cast again rather than changed
+        require!(!proposal.is_ranked, GovernanceError::RankedBallotRequired);
+        let new_vote_support = proposal.vote_support(new_support)?;
+        require!(
+            reason.len() <= MAX_VOTE_REASON_LEN,
+            GovernanceError::ReasonTooLong
+        );
+
+        let old_support = vote_record.support;
+        require!(old_support != new_support, GovernanceError::VoteUnchanged);
+
+        let voting_power = vote_record.voting_power;
+        let leaders_before = proposal.leading_options();
+        remove_from_tally(proposal, old_support, voting_power)?;
+        add_to_tally(proposal, new_support, voting_power)?;
+        extend_if_lead_changed(
+            proposal,
+            &ctx.accounts.governance,
+            &leaders_before,
+            clock.slot,
+        )?;
+
+        vote_record.support = new_support;
+        vote_record.timestamp = clock.unix_timestamp;
+        vote_record.reason = reason.clone();
+        proposal.chain_vote(&vote_record.leaf());
+        voter_account.change_vote(
+            proposal.stance(old_support),
+            proposal.stance(new_support),
+            clock.slot,
+        );
+
+        emit!(VoteChanged {
+            proposal_id,
+            voter: ctx.accounts.voter.key(),
+            old_support: proposal.vote_support(old_support)?,
+            new_support: new_vote_support,
+            voting_power,
+            reason,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Vote changed on proposal {}: support {} -> {}",
+            proposal_id,
+            old_support,
+            new_support
+        );
+        Ok(())
+    }
+
+    /// Remove a vote entirely while voting is still open
+    ///
+    /// The recorded power leaves its tally bucket and the vote record is
+    /// closed to the voter, who may vote again afterwards.
+    pub fn withdraw_vote(ctx: Context<WithdrawVote>, proposal_id: u64) -> Result<()> {
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &ctx.accounts.vote_record;
+        let voter_account = &mut ctx.accounts.voter_account;
+        let clock = Clock::get()?;
+
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            is_voting_open(proposal, clock.slot),
+            GovernanceError::VotingEnded
+        );
+        require!(!proposal.is_frozen, GovernanceError::ProposalFrozen);
+        require!(
+            !ctx.accounts.governance.allow_early_execution,
+            GovernanceError::VotesFinal
+        );
+
+        let support = vote_record.support;
+        let voting_power = vote_record.voting_power;
+        let leaders_before = proposal.leading_options();
+        remove_from_tally(proposal, support, voting_power)?;
+        proposal.remove_voter(voting_power)?;
+        proposal.chain_vote(&vote_record.withdrawal_leaf());
+        extend_if_lead_changed(
+            proposal,
+            &ctx.accounts.governance,
+            &leaders_before,
+            clock.slot,
+        )?;
+        voter_account.withdraw_vote(proposal.stance(support), clock.slot);
+
+        emit!(voter_account.participation(&ctx.accounts.governance, clock.unix_timestamp));
+        emit!(VoteWithdrawn {
+            proposal_id,
+            voter: ctx.accounts.voter.key(),
+            support: proposal.vote_support(support)?,
+            voting_power,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Vote withdrawn on proposal {}: power={}",
+            proposal_id,
+            voting_power
+        );
+        Ok(())
+    }
+
+    /// Formally abstain on every active proposal passed in remaining accounts
+    ///
+    /// Remaining accounts come in `(proposal, vote_record)` pairs, where
+    /// `vote_record` is the voter's vote PDA for that proposal. Proposals the
+    /// voter already voted on, or that are not open for voting, are skipped.
+    pub fn abstain_all<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AbstainAll<'info>>,
+        reason: String,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        let pairs = ctx.remaining_accounts.chunks_exact(2);
+        require!(
+            pairs.remainder().is_empty(),
+            GovernanceError::InvalidProposal
+        );
+        require!(
+            pairs.len() <= MAX_ABSTAIN_BATCH,
+            GovernanceError::BatchTooLarge
+        );
+        require!(
+            reason.len() <= MAX_VOTE_REASON_LEN,
+            GovernanceError::ReasonTooLong
+        );
+
+        let voter_key = ctx.accounts.voter.key();
+        let voting_power =
+            eligible_vote_weight(&ctx.accounts.governance, &ctx.accounts.voter_account)?;
+
+        let clock = Clock::get()?;
+        let rent = Rent::get()?;
+        let space = 8 + VoteRecord::INIT_SPACE;
+        let mut abstained: u64 = 0;
+
+        for pair in pairs {
+            let (proposal_info, record_info) = (&pair[0], &pair[1]);
+            let mut proposal = Account::<Proposal>::try_from(proposal_info)?;
+            require_keys_eq!(
+                proposal_info.key(),
+                Proposal::address(proposal.id),
+                GovernanceError::InvalidProposal
+            );
+
+            let id_bytes = proposal.id.to_le_bytes();
+            let (record_key, bump) = Pubkey::find_program_address(
+                &[b"vote", id_bytes.as_ref(), voter_key.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(
+                record_info.key(),
+                record_key,
+                GovernanceError::InvalidProposal
+            );
+
+            let votable = proposal.status == ProposalStatus::Active
+                && !proposal.is_frozen
+                && clock.slot >= proposal.start_slot
+                && is_voting_open(&proposal, clock.slot);
+            if !votable || record_info.lamports() > 0 {
+                continue;
+            }
+
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: ctx.accounts.voter.to_account_info(),
+                        to: record_info.clone(),
+                    },
+                    &[&[b"vote", id_bytes.as_ref(), voter_key.as_ref(), &[bump]]],
+                ),
+                rent.minimum_balance(space),
+                space as u64,
+                &crate::ID,
+            )?;
+
+            let abstain = proposal.abstain_option();
+            let vote_record = VoteRecord {
+                voter: voter_key,
+                proposal_id: proposal.id,
+                support: abstain,
+                voting_power,
+                timestamp: clock.unix_timestamp,
+                reason: reason.clone(),
+                rankings: Vec::new(),
+                counted_option: abstain,
+                bump,
+            };
+            vote_record.try_serialize(&mut &mut record_info.try_borrow_mut_data()?[..])?;
+            proposal.chain_vote(&vote_record.leaf());
+
+            add_to_tally(&mut proposal, abstain, voting_power)?;
+            proposal.record_voter(voting_power)?;
+            proposal.exit(&crate::ID)?;
+            ctx.accounts.voter_account.record_vote(2, clock.slot);
+            ctx.accounts.voter_account.lock_until(proposal.end_slot);
+
+            emit!(VoteCast {
+                proposal_id: proposal.id,
+                voter: voter_key,
+                support: VoteSupport::Abstain,
+                voting_power,
+                reason: reason.clone(),
+                timestamp: clock.unix_timestamp,
+            });
+            abstained += 1;
+        }
+
+        if abstained > 0 {
+            emit!(ctx
+                .accounts
+                .voter_account
+                .participation(&ctx.accounts.governance, clock.unix_timestamp));
+        }
+        msg!("Voter abstained on {} proposals", abstained);
+        Ok(())
+    }
+
+    /// Buy `votes` on a proposal with quadratic credits: `votes` costs `votes²`
+    /// from the voter's remaining `credit_budget`
+    pub fn vote_with_credits(
+        ctx: Context<VoteWithCredits>,
+        proposal_id: u64,
+        votes: u64,
+        support: u8,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        let proposal = &mut ctx.accounts.proposal;
+        let vote_record = &mut ctx.accounts.vote_record;
+        let voter_account = &mut ctx.accounts.voter_account;
+        let clock = Clock::get()?;
+
+        require_votable(proposal, proposal_id, clock.slot)?;
+        require!(!proposal.is_ranked, GovernanceError::RankedBallotRequired);
+        let vote_support = proposal.vote_support(support)?;
+        // Credits buy the votes, but the voter must still be eligible to vote
+        eligible_vote_weight(&ctx.accounts.governance, voter_account)?;
+        require!(votes > 0, GovernanceError::NoVotingPower);
+
+        let cost = votes
+            .checked_mul(votes)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        voter_account.credit_budget = voter_account
+            .credit_budget
+            .checked_sub(cost)
+            .ok_or(GovernanceError::InsufficientCredits)?;
+        let governance = &mut ctx.accounts.governance;
+        governance.total_credit_budget = governance.total_credit_budget.saturating_sub(cost);
+
+        vote_record.voter = ctx.accounts.voter.key();
+        vote_record.proposal_id = proposal_id;
+        vote_record.support = support;
+        vote_record.voting_power = votes;
+        vote_record.timestamp = clock.unix_timestamp;
+        vote_record.reason = String::new();
+        vote_record.rankings = Vec::new();
+        vote_record.counted_option = support;
+        vote_record.bump = ctx.bumps.vote_record;
+        proposal.chain_vote(&vote_record.leaf());
+
+        let leaders_before = proposal.leading_options();
+        add_to_tally(proposal, support, votes)?;
+        proposal.record_voter(votes)?;
+        proposal.credit_votes = proposal
+            .credit_votes
+            .checked_add(votes)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        extend_if_lead_changed(
+            proposal,
+            &ctx.accounts.governance,
+            &leaders_before,
+            clock.slot,
+        )?;
+        voter_account.record_vote(proposal.stance(support), clock.slot);
+        voter_account.lock_until(proposal.end_slot);
+
+        emit!(voter_account.participation(&ctx.accounts.governance, clock.unix_timestamp));
+        emit!(CreditsSpent {
+            proposal_id,
+            voter: ctx.accounts.voter.key(),
+            support: vote_support,
+            votes,
+            cost,
+            remaining_budget: voter_account.credit_budget,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "{} credit votes cast on proposal {} for {} credits",
+            votes,
+            proposal_id,
+            cost
+        );
+        Ok(())
+    }
+
+    /// Execute a proposal if it has passed
+    ///
+    /// When the proposal carries an instruction payload it is invoked on
+    /// `execution_target` with the governance PDA as signer. The payload's
+    /// accounts are passed as `remaining_accounts` in instruction order, along
+    /// with the target program account itself.
+    ///
+    /// Quorum turnout is `for + against + abstain` when the governance was
+    /// initialized with `abstain_counts_for_quorum`, and `for + against`
+    /// otherwise; finalization applies the same rule.
+    ///
+    /// With `allow_early_execution` set, a proposal still in its voting period
+    /// whose outcome is already decided (see `outcome_decided`) is finalized
+    /// here first; the execution delay still runs from that point.
+    pub fn execute_proposal<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExecuteProposal<'info>>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        require!(
+            !ctx.accounts.governance.is_paused,
+            GovernanceError::GovernancePaused
+        );
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            !proposal.is_ratification,
+            GovernanceError::RatificationNotExecutable
+        );
+        if proposal.status == ProposalStatus::Active
+            && !proposal.is_frozen
+            && is_voting_open(proposal, clock.slot)
+            && ctx.accounts.governance.allow_early_execution
+            && outcome_decided(proposal, &ctx.accounts.governance)?
+        {
+            close_voting_early(proposal, &clock);
+            apply_outcome(proposal, &mut ctx.accounts.governance, &clock)?;
+        }
+        let governance = &ctx.accounts.governance;
+        if let Some(required) = governance.execution_authority_for(proposal.category) {
+            require_keys_eq!(
+                ctx.accounts.executor.key(),
+                required,
+                GovernanceError::Unauthorized
+            );
+        }
+        require!(
+            proposal.status == ProposalStatus::Succeeded,
+            GovernanceError::ProposalNotSucceeded
+        );
+        require!(!proposal.is_frozen, GovernanceError::ProposalFrozen);
+        // Proposals execute in the order they succeeded; a proposal the queue
+        // already moved past (e.g. revived by a veto override) may still run
+        let order = proposal
+            .finalize_order
+            .ok_or(GovernanceError::OutOfOrderExecution)?;
+        let winning_option = proposal
+            .winning_option
+            .ok_or(GovernanceError::ProposalNotSucceeded)?;
+        require!(
+            order <= governance.execution_sequence,
+            GovernanceError::OutOfOrderExecution
+        );
+        let executable_at = proposal
+            .queued_at_slot
+            .checked_add(governance.execution_delay)
+            .ok_or(GovernanceError::SlotOverflow)?;
+        require!(
+            clock.slot >= executable_at,
+            GovernanceError::TimelockNotElapsed
+        );
+        require!(
+            proposal.voter_count >= governance.min_voters,
+            GovernanceError::TurnoutNotReached
+        );
+
+        // A long-lived snapshot may no longer describe the electorate, so a
+        // stale proposal must also reach quorum against the live voting power.
+        // Turnout counts abstentions only when `abstain_counts_for_quorum` is set.
+        // Failing that is terminal: the proposal is defeated rather than left
+        // executable forever.
+        let staleness = governance.snapshot_staleness_slots;
+        if staleness > 0
+            && clock.slot.saturating_sub(proposal.snapshot_slot) > staleness
+            && !quorum_reached_with(proposal, governance, governance.effective_total_power())?
+        {
+            proposal.status = ProposalStatus::Defeated;
+            proposal.defeat_reason = Some(DefeatReason::QuorumNotReached);
+            ctx.accounts
+                .governance
+                .advance_execution_sequence(proposal)?;
+
+            emit!(ProposalDefeated {
+                proposal_id,
+                reason: DefeatReason::QuorumNotReached,
+                timestamp: clock.unix_timestamp,
+            });
+
+            msg!(
+                "Proposal {} defeated: quorum lost against live voting power",
+                proposal_id
+            );
+            return Ok(());
+        }
+
+        if let Some(target) = &proposal.execution_target {
+            require!(
+                governance.is_execution_target_allowed(target),
+                GovernanceError::ExecutionTargetNotAllowed
+            );
+        }
+
+        if let Some(condition) = &proposal.execution_condition {
+            let oracle = ctx
+                .accounts
+                .oracle
+                .as_ref()
+                .ok_or(GovernanceError::InvalidOracleAccount)?;
+            require_keys_eq!(
+                oracle.key(),
+                condition.oracle,
+                GovernanceError::InvalidOracleAccount
+            );
+            require!(
+                condition.evaluate(&oracle.try_borrow_data()?)?,
+                GovernanceError::ExecutionConditionNotMet
+            );
+        }
+
+        proposal.status = ProposalStatus::Executed;
+        proposal.executed_at = clock.unix_timestamp;
+        ctx.accounts
+            .governance
+            .advance_execution_sequence(proposal)?;
+
+        // Persist before handing control to the payload. The runtime only lets
+        // the payload reach this program again by direct self-invocation, and
+        // such a call would then read `Executed` rather than `Succeeded`. No
+        // test exercises that path; it needs a program-test harness.
+        proposal.exit(&crate::ID)?;
+        ctx.accounts.governance.exit(&crate::ID)?;
+
+        let payload_invoked = match proposal.execution_target {
+            Some(target) if !proposal.instruction_data.is_empty() => {
+                invoke_governance_payload(
+                    target,
+                    &proposal.instruction_data,
+                    &ctx.accounts.governance,
+                    ctx.remaining_accounts,
+                )?;
+                true
+            }
+            _ => false,
+        };
+
+        let disbursed = match proposal.recipient {
+            Some(recipient) => {
+                let recipient_info = ctx
+                    .accounts
+                    .recipient
+                    .as_ref()
+                    .ok_or(GovernanceError::InvalidRecipient)?;
+                require_keys_eq!(
+                    recipient_info.key(),
+                    recipient,
+                    GovernanceError::InvalidRecipient
+                );
+                disburse_from_treasury(&ctx.accounts.treasury, recipient_info, proposal.amount)?;
+                proposal.amount
+            }
+            None => 0,
+        };
+
+        emit!(ProposalExecuted {
+            proposal_id,
+            executor: ctx.accounts.executor.key(),
+            votes_for: proposal.votes_for,
+            votes_against: proposal.votes_against,
+            winning_option,
+            payload_invoked,
+            disbursed,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Proposal {} executed", proposal_id);
+        Ok(())
+    }
+
+    /// Run the next instant-runoff rounds of a ranked-choice proposal whose
+    /// voting period has ended
+    ///
+    /// Writable `VoteRecord` accounts for the proposal are passed as remaining
+    /// accounts. A round that needs ballots not passed stays open in
+    /// `eliminating_option`, so a large electorate is counted over several
+    /// calls; `finalize_proposal` waits for `ranked_tally_complete`.
+    pub fn finalize_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeRound<'info>>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(proposal.is_ranked, GovernanceError::NotRankedChoice);
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(!proposal.is_frozen, GovernanceError::ProposalFrozen);
+        require!(
+            !is_voting_open(proposal, clock.slot),
+            GovernanceError::VotingNotEnded
+        );
+        require!(
+            !proposal.ranked_tally_complete,
+            GovernanceError::RankedTallyComplete
+        );
+
+        let mut ballots: Vec<Account<VoteRecord>> = Vec::new();
+        for info in ctx.remaining_accounts {
+            let ballot = Account::<VoteRecord>::try_from(info)?;
+            require_keys_eq!(
+                info.key(),
+                VoteRecord::address(proposal_id, &ballot.voter),
+                GovernanceError::InvalidVoteRecord
+            );
+            // A second copy would transfer the same ballot twice
+            require!(
+                !ballots.iter().any(|other| other.key() == info.key()),
+                GovernanceError::InvalidVoteRecord
+            );
+            ballots.push(ballot);
+        }
+
+        advance_instant_runoff(proposal, &mut ballots, clock.unix_timestamp)?;
+        for ballot in &ballots {
+            ballot.exit(&crate::ID)?;
+        }
+
+        msg!(
+            "Proposal {} ranked count at round {}, complete={}",
+            proposal_id,
+            proposal.current_round,
+            proposal.ranked_tally_complete
+        );
+        Ok(())
+    }
+
+    /// Resolve an active proposal once voting has ended
+    ///
+    /// Sets `Succeeded` when the proposal passes and `Defeated` otherwise; only
+    /// succeeded proposals can be executed. Callable by anyone. An exact tie
+    /// must go through `resolve_tie` first while sortition is enabled. With
+    /// `allow_early_execution` set, a decided proposal may be finalized before
+    /// voting ends, which closes voting at the current slot.
+    pub fn finalize_proposal(ctx: Context<FinalizeProposal>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            !proposal.is_ratification,
+            GovernanceError::RatificationNotExecutable
+        );
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(!proposal.is_frozen, GovernanceError::ProposalFrozen);
+        if is_voting_open(proposal, clock.slot) {
+            require!(
+                governance.allow_early_execution && outcome_decided(proposal, governance)?,
+                GovernanceError::VotingNotEnded
+            );
+            close_voting_early(proposal, &clock);
+        }
+        require!(
+            !governance.sortition_tiebreak
+                || (proposal.is_binary() && governance.tie_breaks_for)
+                || proposal.leading_options().len() < 2
+                || proposal.tiebreak_winner.is_some()
+                || !quorum_reached(proposal, governance)?,
+            GovernanceError::TieNotResolved
+        );
+
+        let defeat_reason = apply_outcome(proposal, governance, &clock)?;
+
+        msg!(
+            "Proposal {} finalized: {}",
+            proposal_id,
+            if defeat_reason.is_none() {
+                "succeeded"
+            } else {
+                "defeated"
+            }
+        );
+        Ok(())
+    }
+
+    /// Settle a resolved proposal's deposit
+    ///
+    /// The deposit is refunded when the proposal passed its vote (`Succeeded`,
+    /// `Executed` or `ExecutionFailed`) or was defeated with at least
+    /// `DEPOSIT_FORFEIT_SUPPORT_BPS` support; it is forfeited when it was
+    /// defeated with less, vetoed or cancelled (see `Proposal::forfeits_deposit`),
+    /// in which case it goes to the treasury. Only the proposer can claim a
+    /// refund; anyone can settle a forfeit.
+    pub fn claim_deposit(ctx: Context<ClaimDeposit>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            proposal.deposit > 0 && !proposal.deposit_settled,
+            GovernanceError::NoDepositToClaim
+        );
+        require!(
+            proposal.is_terminal() || proposal.status == ProposalStatus::Succeeded,
+            GovernanceError::ProposalNotTerminal
+        );
+
+        require!(
+            !veto_overridable(proposal, &ctx.accounts.governance),
+            GovernanceError::VetoStillOverridable
+        );
+
+        let amount = proposal.deposit;
+        let refunded = !proposal.forfeits_deposit();
+        if refunded {
+            require_keys_eq!(
+                ctx.accounts.caller.key(),
+                proposal.proposer,
+                GovernanceError::Unauthorized
+            );
+        }
+        let destination = if refunded {
+            ctx.accounts.proposer.to_account_info()
+        } else {
+            ctx.accounts.treasury.to_account_info()
+        };
+
+        let vault = ctx.accounts.deposit_vault.to_account_info();
+        let vault_balance = vault.lamports();
+        **vault.try_borrow_mut_lamports()? = vault_balance
+            .checked_sub(amount)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        let destination_balance = destination.lamports();
+        **destination.try_borrow_mut_lamports()? = destination_balance
+            .checked_add(amount)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        proposal.deposit_settled = true;
+
+        emit!(DepositSettled {
+            proposal_id,
+            proposer: proposal.proposer,
+            amount,
+            refunded,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Proposal {} deposit of {} lamports {}",
+            proposal_id,
+            amount,
+            if refunded { "refunded" } else { "forfeited" }
+        );
+        Ok(())
+    }
+
+    /// Fund the treasury that disbursement proposals pay out of
+    pub fn deposit_treasury(ctx: Context<DepositTreasury>, amount: u64) -> Result<()> {
+        require!(amount > 0, GovernanceError::InvalidDisbursement);
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.depositor.to_account_info(),
+                    to: ctx.accounts.treasury.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        emit!(TreasuryDeposited {
+            depositor: ctx.accounts.depositor.key(),
+            amount,
+            balance: ctx.accounts.treasury.to_account_info().lamports(),
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Treasury received {} lamports", amount);
+        Ok(())
+    }
+
+    /// Close a terminal proposal and return its rent to the proposer
+    ///
+    /// Allowed once the proposal can no longer change status, its deposit has
+    /// been settled, and `cleanup_delay` slots have passed since voting ended.
+    /// A proposal at the head of the execution queue moves the queue past it
+    /// first; one still waiting further back cannot be closed, nor can a veto
+    /// that could still be overridden. Callable by anyone.
+    pub fn close_proposal(ctx: Context<CloseProposal>, proposal_id: u64) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(proposal.is_terminal(), GovernanceError::ProposalStillActive);
+        require!(
+            !veto_overridable(proposal, governance),
+            GovernanceError::VetoStillOverridable
+        );
+        require!(
+            proposal.deposit == 0 || proposal.deposit_settled,
+            GovernanceError::DepositNotSettled
+        );
+        let closable_at = proposal
+            .end_slot
+            .checked_add(governance.cleanup_delay)
+            .ok_or(GovernanceError::SlotOverflow)?;
+        require!(
+            clock.slot >= closable_at,
+            GovernanceError::CleanupDelayNotElapsed
+        );
+
+        // `advance_execution_queue` needs the account, so the queue must not
+        // be left waiting on a proposal that no longer exists
+        let sequence_before = governance.execution_sequence;
+        governance.advance_execution_sequence(proposal)?;
+        if governance.execution_sequence != sequence_before {
+            emit!(ExecutionQueueAdvanced {
+                skipped_proposal_id: proposal_id,
+                execution_sequence: governance.execution_sequence,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        let sequence = governance.execution_sequence;
+        require!(
+            !matches!(proposal.finalize_order, Some(order) if order >= sequence),
+            GovernanceError::ProposalStillQueued
+        );
+
+        emit!(ProposalClosed {
+            proposal_id,
+            proposer: proposal.proposer,
+            status: proposal.status,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Proposal {} closed", proposal_id);
+        Ok(())
+    }
+
+    /// Close a vote record on a closed proposal and return its rent to the
+    /// voter. Callable by anyone.
+    pub fn close_vote_record(
+        ctx: Context<CloseVoteRecord>,
+        proposal_id: u64,
+        voter: Pubkey,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.proposal.lamports() == 0,
+            GovernanceError::ProposalStillActive
+        );
+
+        msg!(
+            "Vote record of {} on proposal {} closed",
+            voter,
+            proposal_id
+        );
+        Ok(())
+    }
+
+    /// Defeat an active proposal early when quorum can no longer be reached
+    ///
+    /// Hopeless means current votes plus the power of every voter who has not
+    /// voted yet still fall short of the quorum requirement. Both sides use the
+    /// power snapshot taken when voting opened, so later registrations or
+    /// deregistrations do not change the verdict. Callable by anyone.
+    pub fn abandon_hopeless_proposal(
+        ctx: Context<AbandonHopelessProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(
+            is_voting_open(proposal, clock.slot),
+            GovernanceError::VotingEnded
+        );
+
+        let total_votes = quorum_turnout(proposal, governance)? as u128;
+        let remaining_power = proposal
+            .total_power_snapshot
+            .saturating_sub(proposal.total_applied_power) as u128;
+        require!(
+            total_votes + remaining_power < quorum_votes(proposal, governance)? as u128,
+            GovernanceError::QuorumStillReachable
+        );
+
+        proposal.status = ProposalStatus::Defeated;
+        proposal.defeat_reason = Some(DefeatReason::QuorumMathematicallyUnreachable);
+        governance.release_active_slot(proposal);
+
+        emit!(ProposalDefeated {
+            proposal_id,
+            reason: DefeatReason::QuorumMathematicallyUnreachable,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Proposal {} abandoned: quorum unreachable", proposal_id);
+        Ok(())
+    }
+
+    /// Break an exact for/against tie by sortition once voting has ended
+    ///
+    /// Randomness comes from the most recent `SlotHashes` entry mixed with the
+    /// proposal id; the seed and winner are stored so anyone can re-derive the
+    /// draw. The outcome is fixed once recorded.
+    pub fn resolve_tie(ctx: Context<ResolveTie>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            governance.sortition_tiebreak,
+            GovernanceError::SortitionDisabled
+        );
+        require!(
+            !is_voting_open(proposal, clock.slot),
+            GovernanceError::VotingNotEnded
+        );
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(
+            proposal.tiebreak_winner.is_none(),
+            GovernanceError::TieAlreadyResolved
+        );
+        let leaders = proposal.leading_options();
+        require!(leaders.len() >= 2, GovernanceError::NotTied);
+        require!(
+            quorum_reached(proposal, governance)?,
+            GovernanceError::QuorumNotReached
+        );
+
+        let recent_hash = most_recent_slot_hash(&ctx.accounts.slot_hashes)?;
+        let seed = hashv(&[&recent_hash, &proposal_id.to_le_bytes()]).to_bytes();
+        // Option indices follow vote support encoding; on a binary proposal
+        // 0 = against, 1 = for
+        let winner = select_tied_option(&leaders, &seed);
+
+        proposal.tiebreak_seed = Some(seed);
+        proposal.tiebreak_winner = Some(winner);
+
+        emit!(TieResolved {
+            proposal_id,
+            winner,
+            seed,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Proposal {} tie resolved by sortition: option {}",
+            proposal_id,
+            winner
+        );
+        Ok(())
+    }
+
+    /// Record a failed execution attempt of a passed proposal
+    ///
+    /// A failing CPI aborts the whole Solana transaction, so `execute_proposal`
+    /// can never observe the failure itself and the proposal simply stays
+    /// executable. The authority reports each failed attempt here; once
+    /// `max_execution_attempts` is exhausted the proposal becomes `ExecutionFailed`.
+    pub fn report_execution_failure(
+        ctx: Context<ReportExecutionFailure>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            proposal.status == ProposalStatus::Succeeded,
+            GovernanceError::ProposalNotSucceeded
+        );
+
+        proposal.execution_attempts = proposal.execution_attempts.saturating_add(1);
+        if proposal.execution_attempts >= governance.max_execution_attempts {
+            proposal.status = ProposalStatus::ExecutionFailed;
+        }
+
+        emit!(ExecutionAttemptFailed {
+            proposal_id,
+            attempts: proposal.execution_attempts,
+            max_attempts: governance.max_execution_attempts,
+            exhausted: proposal.status == ProposalStatus::ExecutionFailed,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Proposal {} execution attempt {}/{} failed",
+            proposal_id,
+            proposal.execution_attempts,
+            governance.max_execution_attempts
+        );
+        Ok(())
+    }
+
+    /// Guardian veto power (Class A stakeholders)
+    ///
+    /// May be signed by the guardian or by their unexpired veto deputy; the veto
+    /// is always attributed to the guardian. Each guardian's veto is recorded
+    /// once and contributes the guardian's `veto_weight`. The proposal flips to
+    /// `Vetoed` when the accumulated weight reaches both `veto_threshold` and
+    /// `required_veto_weight`, which scales with the proposal's support.
+    ///
+    /// The guardian must cite an ethics justification score of at least the
+    /// veto ethics bar; it is kept on the veto record with the reason.
+    pub fn veto_proposal(
+        ctx: Context<VetoProposal>,
+        proposal_id: u64,
+        reason: String,
+        ethics_justification_score: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let guardian = &mut ctx.accounts.guardian_account;
+        let veto_record = &mut ctx.accounts.veto_record;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        guardian.require_serving(clock.unix_timestamp)?;
+        authorize_veto_signer(guardian, &ctx.accounts.guardian.key(), clock.slot)?;
+        require!(
+            ctx.accounts
+                .governance
+                .veto_window_open(proposal, clock.slot),
+            GovernanceError::VetoWindowClosed
+        );
+        require!(
+            reason.len() <= MAX_VETO_REASON_LEN,
+            GovernanceError::ReasonTooLong
+        );
+        if guardian.veto_count > 0 {
+            let next_allowed = guardian
+                .last_veto_slot
+                .checked_add(ctx.accounts.governance.veto_cooldown)
+                .ok_or(GovernanceError::SlotOverflow)?;
+            require!(
+                clock.slot >= next_allowed,
+                GovernanceError::VetoCooldownActive
+            );
+        }
+        require!(
+            ethics_justification_score <= MAX_EPI_SCORE,
+            GovernanceError::InvalidScore
+        );
+        require!(
+            ethics_justification_score >= ctx.accounts.governance.veto_ethics_bar(),
+            GovernanceError::EPIBelowThreshold
+        );
+        require!(
+            proposal.status == ProposalStatus::Active
+                || proposal.status == ProposalStatus::Succeeded,
+            GovernanceError::CannotVeto
+        );
+
+        veto_record.guardian = guardian.guardian;
+        veto_record.proposal_id = proposal_id;
+        veto_record.ethics_justification_score = ethics_justification_score;
+        veto_record.reason = reason.clone();
+        veto_record.timestamp = clock.unix_timestamp;
+        veto_record.bump = ctx.bumps.veto_record;
+
+        guardian.veto_count += 1;
+        guardian.last_vetoed_proposal = Some(proposal_id);
+        guardian.last_veto_slot = clock.slot;
+        guardian.last_veto_reason = reason.clone();
+        guardian.apply_veto_decay(&ctx.accounts.governance, clock.slot);
+        guardian.recent_veto_score += 1;
+
+        proposal.veto_power_accumulated = proposal
+            .veto_power_accumulated
+            .checked_add(guardian.veto_weight)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        let governance = &ctx.accounts.governance;
+        let required = required_veto_weight(proposal, governance.base_veto_threshold)
+            .max(governance.veto_threshold);
+
+        emit!(GuardianVetoRecorded {
+            proposal_id,
+            guardian: guardian.guardian,
+            accumulated: proposal.veto_power_accumulated,
+            required,
+            timestamp: clock.unix_timestamp,
+        });
+
+        if proposal.veto_power_accumulated < required {
+            msg!(
+                "Guardian veto recorded on proposal {}: {}/{}",
+                proposal_id,
+                proposal.veto_power_accumulated,
+                required
+            );
+            return Ok(());
+        }
+
+        if proposal.status == ProposalStatus::Active {
+            ctx.accounts.governance.release_active_slot(proposal);
+        }
+        proposal.status = ProposalStatus::Vetoed;
+
+        emit!(ProposalVetoed {
+            proposal_id,
+            guardian: guardian.guardian,
+            reason,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Proposal {} vetoed by guardian", proposal_id);
+        Ok(())
+    }
+
+    /// Reverse a guardian veto when `support_votes` exceeds `override_threshold`
+    /// (basis points) of the proposal's power snapshot
+    ///
+    /// A proposal still in its voting window returns to `Active`; otherwise it
+    /// becomes `Succeeded` and its execution timelock restarts. Each proposal
+    /// can be overridden at most once. Callable by anyone.
+    pub fn override_veto(ctx: Context<OverrideVeto>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            proposal.status == ProposalStatus::Vetoed,
+            GovernanceError::ProposalNotVetoed
+        );
+        require!(
+            !proposal.veto_overridden,
+            GovernanceError::VetoAlreadyOverridden
+        );
+        require!(
+            governance.override_threshold > 0,
+            GovernanceError::VetoOverrideDisabled
+        );
+        require!(
+            override_threshold_met(proposal, governance),
+            GovernanceError::OverrideThresholdNotMet
+        );
+
+        proposal.veto_overridden = true;
+        if is_voting_open(proposal, clock.slot) {
+            proposal.status = ProposalStatus::Active;
+            if !proposal.is_ratification {
+                governance.category_active_counts[proposal.category as usize] += 1;
+            }
+        } else {
+            if let Some(reason) = evaluate_outcome(proposal, governance)? {
+                return Err(reason.into());
+            }
+            proposal.status = ProposalStatus::Succeeded;
+            proposal.winning_option = winning_option(proposal, governance);
+            proposal.queued_at_slot = clock.slot;
+            if proposal.is_ratification {
+                proposal.ratification_result = Some(true);
+            } else {
+                governance.enqueue_for_execution(proposal)?;
+            }
+        }
+
+        emit!(VetoOverridden {
+            proposal_id,
+            votes_for: proposal.votes_for,
+            total_power_snapshot: proposal.total_power_snapshot,
+            status: proposal.status,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Veto on proposal {} overridden", proposal_id);
+        Ok(())
+    }
+
+    /// Move the execution queue past its head proposal once that proposal
+    /// can no longer execute (vetoed, failed or defeated). Callable by anyone.
+    pub fn advance_execution_queue(
+        ctx: Context<AdvanceExecutionQueue>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            proposal.finalize_order == Some(governance.execution_sequence),
+            GovernanceError::OutOfOrderExecution
+        );
+        require!(
+            proposal.status != ProposalStatus::Succeeded,
+            GovernanceError::ProposalStillQueued
+        );
+        governance.advance_execution_sequence(proposal)?;
+
+        emit!(ExecutionQueueAdvanced {
+            skipped_proposal_id: proposal_id,
+            execution_sequence: governance.execution_sequence,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Execution queue advanced past proposal {}", proposal_id);
+        Ok(())
+    }
+
+    /// Freeze a proposal for investigation without vetoing it
+    ///
+    /// A frozen proposal keeps its status but cannot be voted on, finalized
+    /// or executed until a guardian unfreezes it.
+    pub fn freeze_proposal(ctx: Context<FreezeProposal>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        ctx.accounts
+            .guardian_account
+            .require_serving(clock.unix_timestamp)?;
+        require!(
+            proposal.status == ProposalStatus::Active
+                || proposal.status == ProposalStatus::Succeeded,
+            GovernanceError::ProposalNotActive
+        );
+        require!(!proposal.is_frozen, GovernanceError::ProposalFrozen);
+
+        proposal.is_frozen = true;
+        proposal.frozen_at_slot = clock.slot;
+
+        emit!(ProposalFrozen {
+            proposal_id,
+            guardian: ctx.accounts.guardian.key(),
+            slot: clock.slot,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Proposal {} frozen", proposal_id);
+        Ok(())
+    }
+
+    /// Lift a guardian freeze
+    ///
+    /// If the proposal was frozen while voting was open, `end_slot` moves out
+    /// by the frozen duration so voters get back the time they lost.
+    pub fn unfreeze_proposal(ctx: Context<FreezeProposal>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        ctx.accounts
+            .guardian_account
+            .require_serving(clock.unix_timestamp)?;
+        require!(proposal.is_frozen, GovernanceError::ProposalNotFrozen);
+
+        let frozen_slots = clock.slot.saturating_sub(proposal.frozen_at_slot);
+        if proposal.status == ProposalStatus::Active
+            && is_voting_open(proposal, proposal.frozen_at_slot)
+        {
+            proposal.end_slot = proposal
+                .end_slot
+                .checked_add(frozen_slots)
+                .ok_or(GovernanceError::SlotOverflow)?;
+            proposal.end_ts = slot_timestamp(&clock, proposal.end_slot);
+        }
+        proposal.is_frozen = false;
+        proposal.frozen_at_slot = 0;
+
+        emit!(ProposalUnfrozen {
+            proposal_id,
+            guardian: ctx.accounts.guardian.key(),
+            frozen_slots,
+            end_slot: proposal.end_slot,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Proposal {} unfrozen after {} slots",
+            proposal_id,
+            frozen_slots
+        );
+        Ok(())
+    }
+
+    /// Temporarily let a deputy exercise this guardian's veto
+    pub fn delegate_veto(
+        ctx: Context<ManageVetoDelegation>,
+        deputy: Pubkey,
+        expires_at_slot: u64,
+    ) -> Result<()> {
+        let guardian_account = &mut ctx.accounts.guardian_account;
+        let clock = Clock::get()?;
+
+        guardian_account.require_serving(clock.unix_timestamp)?;
+        require!(
+            deputy != guardian_account.guardian,
+            GovernanceError::InvalidVetoDelegate
+        );
+        require!(
+            expires_at_slot > clock.slot,
+            GovernanceError::VetoDelegationExpired
+        );
+
+        guardian_account.veto_delegate = Some(VetoDelegation {
+            deputy,
+            expires_at_slot,
+        });
+
+        emit!(VetoDelegated {
+            guardian: guardian_account.guardian,
+            deputy,
+            expires_at_slot,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Veto delegated to {} until slot {}",
+            deputy,
+            expires_at_slot
+        );
+        Ok(())
+    }
+
+    /// Clear a guardian's veto delegation before it expires
+    pub fn revoke_veto_delegation(ctx: Context<ManageVetoDelegation>) -> Result<()> {
+        let guardian_account = &mut ctx.accounts.guardian_account;
+        guardian_account.veto_delegate = None;
+
+        emit!(VetoDelegationRevoked {
+            guardian: guardian_account.guardian,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Veto delegation revoked");
+        Ok(())
+    }
+
+    /// Correct a proposal's EPI scores and category before anyone has voted on it
+    ///
+    /// The new values go through the same gates as `submit_proposal`: the
+    /// scores must clear the threshold outright, and a new category must exist
+    /// and have room under `max_active_per_category`. Additional sub-score
+    /// dimensions are kept.
+    pub fn correct_proposal_scores(
+        ctx: Context<CorrectProposalScores>,
+        proposal_id: u64,
+        profit_score: u64,
+        ethics_score: u64,
+        category: u8,
+    ) -> Result<()> {
+        let governance = &mut ctx.accounts.governance;
+        let proposal = &mut ctx.accounts.proposal;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require_correctable(proposal)?;
+        let old_category = proposal.category;
+        if category != old_category {
+            require_category_capacity(governance, category)?;
+        }
+
+        let extra_sub_scores: Vec<SubScore> = proposal
+            .sub_scores
+            .iter()
+            .filter(|score| {
+                score.dimension != DIMENSION_PROFIT && score.dimension != DIMENSION_ETHICS
+            })
+            .copied()
+            .collect();
+        let evaluation = evaluate_epi(
+            governance,
+            profit_score,
+            ethics_score,
+            &extra_sub_scores,
+            proposal.epi_threshold,
+        )?;
+        require!(
+            evaluation.meets_threshold,
+            GovernanceError::EPIBelowThreshold
+        );
+        let epi_score = evaluation.epi_score;
+
+        let old_epi_score = proposal.epi_score;
+        proposal.epi_score = epi_score;
+        proposal.profit_score = profit_score;
+        proposal.ethics_score = ethics_score;
+        proposal.sub_scores = evaluation.sub_scores;
+        proposal.composite_epi = evaluation.composite.unwrap_or(0);
+        if category != old_category {
+            governance.release_active_slot(proposal);
+            proposal.category = category;
+            if !proposal.is_ratification {
+                governance.category_active_counts[category as usize] += 1;
+            }
+        }
+
+        emit!(ProposalScoresCorrected {
+            proposal_id,
+            old_epi_score,
+            new_epi_score: epi_score,
+            profit_score,
+            ethics_score,
+            old_category,
+            category,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Proposal {} scores corrected: {} -> {}",
+            proposal_id,
+            old_epi_score,
+            epi_score
+        );
+        Ok(())
+    }
+
+    /// Link an off-chain deliberation record to an active proposal
+    pub fn attach_deliberation(
+        ctx: Context<AttachDeliberation>,
+        proposal_id: u64,
+        deliberation_ipfs_hash: [u8; 32],
+        summary: String,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let signer = ctx.accounts.signer.key();
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            signer == proposal.proposer || signer == ctx.accounts.governance.authority,
+            GovernanceError::Unauthorized
+        );
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(summary.len() <= 128, GovernanceError::SummaryTooLong);
+        require!(
+            proposal.deliberations.len() < MAX_DELIBERATIONS,
+            GovernanceError::TooManyDeliberations
+        );
+
+        proposal.deliberations.push(DeliberationRef {
+            ipfs_hash: deliberation_ipfs_hash,
+            summary: summary.clone(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        emit!(DeliberationAttached {
+            proposal_id,
+            attached_by: signer,
+            ipfs_hash: deliberation_ipfs_hash,
+            summary,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Deliberation attached to proposal {}", proposal_id);
+        Ok(())
+    }
+
+    /// Correct the caller's own proposal text or document hash before voting starts
+    pub fn amend_proposal(
+        ctx: Context<AmendProposal>,
+        proposal_id: u64,
+        new_title: String,
+        new_description: String,
+        new_ipfs_hash: [u8; 32],
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require_keys_eq!(
+            ctx.accounts.proposer.key(),
+            proposal.proposer,
+            GovernanceError::Unauthorized
+        );
+        require!(
+            proposal.status == ProposalStatus::Active && clock.slot < proposal.start_slot,
+            GovernanceError::CannotAmend
+        );
+        require!(
+            new_title.len() <= ctx.accounts.governance.max_title_len as usize,
+            GovernanceError::TitleTooLong
+        );
+        require!(
+            new_description.len() <= ctx.accounts.governance.max_description_len as usize,
+            GovernanceError::DescriptionTooLong
+        );
+
+        proposal.title = new_title.clone();
+        proposal.description = new_description;
+        proposal.ipfs_hash = new_ipfs_hash;
+
+        emit!(ProposalAmended {
+            proposal_id,
+            title: new_title,
+            ipfs_hash: new_ipfs_hash,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Proposal {} amended", proposal_id);
+        Ok(())
+    }
+
+    /// Withdraw the caller's own proposal before anyone has voted on it
+    pub fn cancel_proposal(ctx: Context<CancelProposal>, proposal_id: u64) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require_keys_eq!(
+            ctx.accounts.proposer.key(),
+            proposal.proposer,
+            GovernanceError::Unauthorized
+        );
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(
+            total_votes(proposal)? == 0,
+            GovernanceError::ProposalHasVotes
+        );
+
+        proposal.status = ProposalStatus::Cancelled;
+        proposal.cancel_reason = Some(CancelReason::WithdrawnByProposer);
+        governance.release_active_slot(proposal);
+
+        emit!(ProposalCancelled {
+            proposal_id,
+            canceler: ctx.accounts.proposer.key(),
+            reason: CancelReason::WithdrawnByProposer,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Proposal {} cancelled by proposer", proposal_id);
+        Ok(())
+    }
+
+    /// Cancel an active proposal whose proposer no longer holds `min_proposal_power`
+    ///
+    /// Callable by anyone, so the qualification check holds for the whole
+    /// lifecycle rather than only at submission.
+    pub fn invalidate_underqualified_proposal(
+        ctx: Context<InvalidateProposal>,
+        proposal_id: u64,
+    ) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let governance = &mut ctx.accounts.governance;
+        let proposer_power = ctx.accounts.proposer_account.voting_power;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            proposal.status == ProposalStatus::Active,
+            GovernanceError::ProposalNotActive
+        );
+        require!(
+            proposer_power < governance.min_proposal_power,
+            GovernanceError::ProposerStillQualified
+        );
+
+        proposal.status = ProposalStatus::Cancelled;
+        proposal.cancel_reason = Some(CancelReason::ProposerDisqualified);
+        governance.release_active_slot(proposal);
+
+        emit!(ProposalCancelled {
+            proposal_id,
+            canceler: ctx.accounts.caller.key(),
+            reason: CancelReason::ProposerDisqualified,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Proposal {} cancelled: proposer power {} below {}",
+            proposal_id,
+            proposer_power,
+            governance.min_proposal_power
+        );
+        Ok(())
+    }
+
+    /// Seal a proposal's voting record under a Merkle root of its vote leaves
+    ///
+    /// Each leaf must be `VoteRecord::leaf()` of one vote cast on the proposal;
+    /// the leaf count has to match `proposal.voter_count`.
+    pub fn seal_proposal(
+        ctx: Context<SealProposal>,
+        proposal_id: u64,
+        vote_leaves: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let proposal = &mut ctx.accounts.proposal;
+        let clock = Clock::get()?;
+
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        require!(
+            !is_voting_open(proposal, clock.slot),
+            GovernanceError::VotingNotEnded
+        );
+        require!(
+            proposal.vote_merkle_root.is_none(),
+            GovernanceError::ProposalAlreadySealed
+        );
+        require!(
+            vote_leaves.len() as u64 == proposal.voter_count,
+            GovernanceError::VoteCountMismatch
+        );
+
+        let root = compute_merkle_root(&vote_leaves);
+        proposal.vote_merkle_root = Some(root);
+
+        emit!(ProposalSealed {
+            proposal_id,
+            vote_merkle_root: root,
+            voter_count: proposal.voter_count,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Proposal {} sealed with {} votes",
+            proposal_id,
+            proposal.voter_count
+        );
+        Ok(())
+    }
+
+    /// Log AI thought process
+    ///
+    /// Reasoning usually precedes submission, so the proposal account is only
+    /// checked against `proposal_id` when it is passed.
+    pub fn log_thought(
+        ctx: Context<LogThought>,
+        proposal_id: u64,
+        params: ThoughtParams,
+    ) -> Result<()> {
+        let ThoughtParams {
+            agent_id,
+            action,
+            epi_score,
+            reasoning_hash,
+            inputs_hash,
+            outputs_hash,
+        } = params;
+        let thought_log = &mut ctx.accounts.thought_log;
+        let clock = Clock::get()?;
+
+        require!(agent_id.len() <= 32, GovernanceError::AgentIdTooLong);
+        require!(action.len() <= 64, GovernanceError::ActionTooLong);
+        if let Some(proposal) = &ctx.accounts.proposal {
+            require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+        }
+
+        thought_log.proposal_id = proposal_id;
+        thought_log.agent_id = agent_id.clone();
+        thought_log.action = action;
+        thought_log.epi_score = epi_score;
+        thought_log.reasoning_hash = reasoning_hash;
+        thought_log.inputs_hash = inputs_hash;
+        thought_log.outputs_hash = outputs_hash;
+        thought_log.timestamp = clock.unix_timestamp;
+        thought_log.logger = ctx.accounts.logger.key();
+        thought_log.bump = ctx.bumps.thought_log;
+
+        let governance = &mut ctx.accounts.governance;
+        governance.thought_count = governance
+            .thought_count
+            .checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+        emit!(ThoughtLogged {
+            proposal_id,
+            agent_id,
+            thought_hash: reasoning_hash,
+            timestamp: thought_log.timestamp,
+        });
+
+        msg!("Thought logged for agent: {}", thought_log.agent_id);
+        Ok(())
+    }
+
+    /// Register a voter with voting power
+    pub fn register_voter(ctx: Context<RegisterVoter>, voting_power: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let voter_account = &mut ctx.accounts.voter_account;
+        let governance = &mut ctx.accounts.governance;
+
+        voter_account.set_inner(VoterAccount::new(
+            ctx.accounts.voter.key(),
+            voting_power,
+            Clock::get()?.unix_timestamp,
+            governance.proposal_count,
+            ctx.bumps.voter_account,
+        ));
+        governance.add_voting_power(voting_power)?;
+
+        emit!(VoterRegistered {
+            voter: ctx.accounts.voter.key(),
+            voting_power,
+            timestamp: voter_account.registered_at,
+        });
+
+        msg!("Voter registered with power: {}", voting_power);
+        Ok(())
+    }
+
+    /// Register up to `MAX_REGISTRATION_BATCH` voters at once
+    ///
+    /// Remaining accounts are the voter PDAs (`[b"voter", voter]`) in the same
+    /// order as `voters`; the authority pays for every account. Council
+    /// co-signers, if any, are passed after them.
+    pub fn register_voters_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RegisterVotersBatch<'info>>,
+        voters: Vec<VoterRegistration>,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            voters.len() <= MAX_REGISTRATION_BATCH,
+            GovernanceError::BatchTooLarge
+        );
+        require!(
+            voters.len()
+                == ctx
+                    .remaining_accounts
+                    .iter()
+                    .filter(|info| !info.is_signer)
+                    .count(),
+            GovernanceError::InvalidVoterAccount
+        );
+
+        let governance = &mut ctx.accounts.governance;
+        let registered_at = Clock::get()?.unix_timestamp;
+        let rent = Rent::get()?;
+        let space = 8 + VoterAccount::INIT_SPACE;
+
+        let voter_infos = ctx.remaining_accounts.iter().filter(|info| !info.is_signer);
+        for (registration, info) in voters.iter().zip(voter_infos) {
+            let (voter_key, bump) =
+                Pubkey::find_program_address(&[b"voter", registration.voter.as_ref()], &crate::ID);
+            require_keys_eq!(info.key(), voter_key, GovernanceError::InvalidVoterAccount);
+            require!(
+                info.lamports() == 0,
+                GovernanceError::VoterAlreadyRegistered
+            );
+
+            anchor_lang::system_program::create_account(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::CreateAccount {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: info.clone(),
+                    },
+                    &[&[b"voter", registration.voter.as_ref(), &[bump]]],
+                ),
+                rent.minimum_balance(space),
+                space as u64,
+                &crate::ID,
+            )?;
+
+            let voter_account = VoterAccount::new(
+                registration.voter,
+                registration.voting_power,
+                registered_at,
+                governance.proposal_count,
+                bump,
+            );
+            voter_account.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+            governance.add_voting_power(registration.voting_power)?;
+
+            emit!(VoterRegistered {
+                voter: registration.voter,
+                voting_power: registration.voting_power,
+                timestamp: registered_at,
+            });
+        }
+
+        msg!("Registered {} voters", voters.len());
+        Ok(())
+    }
+
+    /// Remove a voter and their power from the electorate
+    ///
+    /// If the voter delegated their power, pass the delegate's account as
+    /// `delegate_account`. Every voter who delegated to this voter must be
+    /// passed in remaining accounts so their delegation is cleared as well;
+    /// signer accounts among them are treated as council co-signers.
+    pub fn deregister_voter<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeregisterVoter<'info>>,
+        voter: Pubkey,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let voter_account = &mut ctx.accounts.voter_account;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            !voter_account.is_power_locked(Clock::get()?.slot),
+            GovernanceError::VotingPowerLocked
+        );
+
+        if let Some(delegate) = voter_account.delegate {
+            let delegate_account = ctx
+                .accounts
+                .delegate_account
+                .as_mut()
+                .ok_or(GovernanceError::NotDelegated)?;
+            require_keys_eq!(
+                delegate_account.voter,
+                delegate,
+                GovernanceError::NotDelegated
+            );
+            delegate_account.delegated_power = delegate_account
+                .delegated_power
+                .saturating_sub(voter_account.voting_power);
+        }
+
+        for info in ctx.remaining_accounts.iter().filter(|info| !info.is_signer) {
+            let mut delegator = Account::<VoterAccount>::try_from(info)?;
+            require_keys_eq!(
+                info.key(),
+                VoterAccount::address(&delegator.voter),
+                GovernanceError::NotDelegated
+            );
+            require!(
+                delegator.delegate == Some(voter),
+                GovernanceError::NotDelegated
+            );
+
+            voter_account.delegated_power = voter_account
+                .delegated_power
+                .saturating_sub(delegator.voting_power);
+            delegator.delegate = None;
+            delegator.exit(&crate::ID)?;
+        }
+        require!(
+            voter_account.delegated_power == 0,
+            GovernanceError::DelegatorsRemaining
+        );
+
+        governance.remove_voting_power(voter_account.voting_power)?;
+        governance.total_credit_budget = governance
+            .total_credit_budget
+            .saturating_sub(voter_account.credit_budget);
+
+        emit!(VoterDeregistered {
+            voter,
+            voting_power: voter_account.voting_power,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Voter deregistered, removing power: {}",
+            voter_account.voting_power
+        );
+        Ok(())
+    }
+
+    /// Change a registered voter's voting power
+    ///
+    /// Votes already cast keep the power recorded on their vote records; only
+    /// later votes use the new power. If the voter delegated their power, pass
+    /// the delegate's account as `delegate_account` so its delegated total follows.
+    pub fn update_voting_power(
+        ctx: Context<UpdateVotingPower>,
+        voter: Pubkey,
+        new_power: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let voter_account = &mut ctx.accounts.voter_account;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            !voter_account.is_power_locked(Clock::get()?.slot),
+            GovernanceError::VotingPowerLocked
+        );
+        let old_power = voter_account.voting_power;
+
+        if let Some(delegate) = voter_account.delegate {
+            let delegate_account = ctx
+                .accounts
+                .delegate_account
+                .as_mut()
+                .ok_or(GovernanceError::NotDelegated)?;
+            require_keys_eq!(
+                delegate_account.voter,
+                delegate,
+                GovernanceError::NotDelegated
+            );
+            delegate_account.delegated_power = delegate_account
+                .delegated_power
+                .checked_sub(old_power)
+                .and_then(|power| power.checked_add(new_power))
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
+        }
+
+        governance.remove_voting_power(old_power)?;
+        governance.add_voting_power(new_power)?;
+        voter_account.voting_power = new_power;
+
+        emit!(VotingPowerUpdated {
+            voter,
+            old_power,
+            new_power,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Voting power updated: {} -> {}", old_power, new_power);
+        Ok(())
+    }
+
+    /// Set the caller's notification preferences (`NOTIFY_*` bits) for indexers
+    pub fn set_notification_prefs(ctx: Context<SetNotificationPrefs>, prefs: u8) -> Result<()> {
+        require!(
+            prefs & !NOTIFY_ALL == 0,
+            GovernanceError::InvalidNotificationPrefs
+        );
+
+        let voter_account = &mut ctx.accounts.voter_account;
+        voter_account.notification_prefs = prefs;
+
+        emit!(NotificationPrefsUpdated {
+            voter: voter_account.voter,
+            prefs,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Notification prefs set to {:#04x}", prefs);
+        Ok(())
+    }
+
+    /// Delegate the caller's voting power to another registered voter
+    ///
+    /// Delegation is one level deep: the delegate votes with their own power
+    /// plus everything delegated to them, while the delegator cannot vote until
+    /// they undelegate.
+    pub fn delegate(ctx: Context<Delegate>, delegate: Pubkey) -> Result<()> {
+        let voter_account = &mut ctx.accounts.voter_account;
+        let delegate_account = &mut ctx.accounts.delegate_account;
+        let slot = Clock::get()?.slot;
+
+        require!(
+            delegate != voter_account.voter,
+            GovernanceError::SelfDelegation
+        );
+        require!(
+            voter_account.delegate.is_none(),
+            GovernanceError::AlreadyDelegated
+        );
+        require!(
+            delegate_account.delegate != Some(voter_account.voter),
+            GovernanceError::DelegationCycle
+        );
+        // Delegation is single-level: a delegate holds no delegation of its
+        // own and a voter holding delegated power cannot pass it on
+        require!(
+            delegate_account.delegate.is_none() && voter_account.delegated_power == 0,
+            GovernanceError::DelegationChain
+        );
+        require!(
+            !voter_account.is_power_locked(slot) && !delegate_account.is_power_locked(slot),
+            GovernanceError::VotingPowerLocked
+        );
+
+        delegate_account.delegated_power = delegate_account
+            .delegated_power
+            .checked_add(voter_account.voting_power)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        voter_account.delegate = Some(delegate);
+
+        emit!(VoteDelegated {
+            delegator: voter_account.voter,
+            delegate,
+            voting_power: voter_account.voting_power,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Voting power {} delegated to {}",
+            voter_account.voting_power,
+            delegate
+        );
+        Ok(())
+    }
+
+    /// Take back voting power previously delegated
+    pub fn undelegate(ctx: Context<Undelegate>) -> Result<()> {
+        let voter_account = &mut ctx.accounts.voter_account;
+        let delegate_account = &mut ctx.accounts.delegate_account;
+        let slot = Clock::get()?.slot;
+
+        // The delegate may already have voted with this power
+        require!(
+            !voter_account.is_power_locked(slot) && !delegate_account.is_power_locked(slot),
+            GovernanceError::VotingPowerLocked
+        );
+
+        delegate_account.delegated_power = delegate_account
+            .delegated_power
+            .saturating_sub(voter_account.voting_power);
+        voter_account.delegate = None;
+
+        emit!(VoteUndelegated {
+            delegator: voter_account.voter,
+            delegate: delegate_account.voter,
+            voting_power: voter_account.voting_power,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Voting power reclaimed from {}", delegate_account.voter);
+        Ok(())
+    }
+
+    /// Add a guardian whose vetoes carry `veto_weight`
+    pub fn add_guardian(ctx: Context<AddGuardian>, veto_weight: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let guardian_account = &mut ctx.accounts.guardian_account;
+        let governance = &mut ctx.accounts.governance;
+        let clock = Clock::get()?;
+
+        require!(veto_weight > 0, GovernanceError::InvalidVetoWeight);
+        require!(
+            governance.guardian_count < governance.max_guardians,
+            GovernanceError::GuardianCapReached
+        );
+        governance.guardian_count = governance
+            .guardian_count
+            .checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        governance.total_guardian_weight = governance
+            .total_guardian_weight
+            .checked_add(veto_weight)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+
+        guardian_account.guardian = ctx.accounts.new_guardian.key();
+        guardian_account.is_active = true;
+        guardian_account.veto_weight = veto_weight;
+        guardian_account.veto_count = 0;
+        guardian_account.last_vetoed_proposal = None;
+        guardian_account.last_veto_slot = 0;
+        guardian_account.last_veto_reason = String::new();
+        guardian_account.veto_delegate = None;
+        guardian_account.recent_veto_score = 0;
+        guardian_account.veto_score_updated_slot = clock.slot;
+        guardian_account.added_at = clock.unix_timestamp;
+        guardian_account.term_end = governance.guardian_term_end(clock.unix_timestamp)?;
+        guardian_account.bump = ctx.bumps.guardian_account;
+
+        emit!(GuardianAdded {
+            guardian: ctx.accounts.new_guardian.key(),
+            veto_weight,
+            term_end: guardian_account.term_end,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Guardian added: {}", ctx.accounts.new_guardian.key());
+        Ok(())
+    }
+
+    /// Start a fresh `guardian_term_length` term for an active guardian
+    pub fn renew_guardian(ctx: Context<RenewGuardian>, guardian: Pubkey) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let guardian_account = &mut ctx.accounts.guardian_account;
+        let clock = Clock::get()?;
+
+        require!(guardian_account.is_active, GovernanceError::NotGuardian);
+        guardian_account.term_end = ctx
+            .accounts
+            .governance
+            .guardian_term_end(clock.unix_timestamp)?;
+
+        emit!(GuardianRenewed {
+            guardian,
+            term_end: guardian_account.term_end,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!(
+            "Guardian {} renewed until {}",
+            guardian,
+            guardian_account.term_end
+        );
+        Ok(())
+    }
+
+    /// Deactivate a guardian, revoking their veto power immediately
+    ///
+    /// The account is kept so later veto attempts fail with `NotGuardian`.
+    /// Veto thresholds above the remaining guardian weight are lowered to it.
+    pub fn remove_guardian(ctx: Context<RemoveGuardian>, guardian: Pubkey) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let guardian_account = &mut ctx.accounts.guardian_account;
+        let governance = &mut ctx.accounts.governance;
+
+        require!(guardian_account.is_active, GovernanceError::NotGuardian);
+        guardian_account.is_active = false;
+        guardian_account.veto_delegate = None;
+
+        governance.guardian_count = governance.guardian_count.saturating_sub(1);
+        governance.total_guardian_weight = governance
+            .total_guardian_weight
+            .saturating_sub(guardian_account.veto_weight);
+        let max_threshold = governance.total_guardian_weight.max(1);
+        governance.veto_threshold = governance.veto_threshold.min(max_threshold);
+        governance.base_veto_threshold = governance.base_veto_threshold.min(max_threshold);
+
+        emit!(GuardianRemoved {
+            guardian,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Guardian removed: {}", guardian);
+        Ok(())
+    }
+
+    /// Update EPI threshold
+    pub fn update_epi_threshold(ctx: Context<UpdateGovernance>, new_threshold: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            new_threshold <= MAX_EPI_SCORE,
+            GovernanceError::InvalidScore
+        );
+        let governance = &mut ctx.accounts.governance;
         let old_threshold = governance.epi_threshold;
         governance.epi_threshold = new_threshold;
 
-        emit!(EPIThresholdUpdated {
-            old_threshold,
-            new_threshold,
-            timestamp: Clock::get()?.unix_timestamp,
-        });
+        emit!(EPIThresholdUpdated {
+            old_threshold,
+            new_threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "EPI threshold updated: {} -> {}",
+            old_threshold,
+            new_threshold
+        );
+        Ok(())
+    }
+
+    /// Update how many slots after voting ends guardians may still veto (0 = no limit)
+    pub fn update_veto_window(ctx: Context<UpdateGovernance>, veto_window: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.veto_window = veto_window;
+
+        msg!("Veto window updated: {} slots", veto_window);
+        Ok(())
+    }
+
+    /// Update the slots a guardian must wait between vetoes (0 = no limit)
+    pub fn update_veto_cooldown(ctx: Context<UpdateGovernance>, veto_cooldown: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.veto_cooldown = veto_cooldown;
+
+        msg!("Veto cooldown updated: {} slots", veto_cooldown);
+        Ok(())
+    }
+
+    /// Update the ethics justification score guardian vetoes must cite;
+    /// 0 falls back to the EPI threshold
+    pub fn update_veto_ethics_threshold(
+        ctx: Context<UpdateGovernance>,
+        veto_ethics_threshold: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            veto_ethics_threshold <= MAX_EPI_SCORE,
+            GovernanceError::InvalidScore
+        );
+        ctx.accounts.governance.veto_ethics_threshold = veto_ethics_threshold;
+
+        msg!("Veto ethics threshold updated: {}", veto_ethics_threshold);
+        Ok(())
+    }
+
+    /// Average EPI score of the most recently submitted proposals
+    pub fn get_average_epi(ctx: Context<ReadGovernance>) -> Result<u64> {
+        Ok(ctx.accounts.governance.average_epi())
+    }
+
+    /// PDA the next submitted proposal will occupy
+    pub fn next_proposal_address(ctx: Context<ReadGovernance>) -> Result<Pubkey> {
+        Ok(Proposal::address(ctx.accounts.governance.proposal_count))
+    }
+
+    /// PDA of the vote record `voter` would create on `proposal_id`
+    pub fn vote_record_address(
+        _ctx: Context<ReadGovernance>,
+        proposal_id: u64,
+        voter: Pubkey,
+    ) -> Result<Pubkey> {
+        Ok(VoteRecord::address(proposal_id, &voter))
+    }
+
+    /// Aggregate turnout, pass, and veto rates over terminal proposals passed
+    /// as remaining accounts
+    ///
+    /// Turnout is measured against each proposal's power snapshot, as quorum
+    /// is, so later registrations do not rewrite historical turnout.
+    pub fn participation_metrics(ctx: Context<ReadGovernance>) -> Result<ParticipationMetrics> {
+        let count = ctx.remaining_accounts.len() as u128;
+        require!(count > 0, GovernanceError::InvalidProposal);
+
+        let mut turnout_bps_sum: u128 = 0;
+        let mut passed: u128 = 0;
+        let mut vetoed: u128 = 0;
+        let mut voter_count_sum: u128 = 0;
+
+        for info in ctx.remaining_accounts.iter() {
+            let proposal = load_proposal(info)?;
+            require!(proposal.is_terminal(), GovernanceError::ProposalNotTerminal);
+
+            let total_votes = proposal.option_turnout() + proposal.votes_abstain as u128;
+            if proposal.total_power_snapshot > 0 {
+                turnout_bps_sum += total_votes * 10000 / proposal.total_power_snapshot as u128;
+            }
+            if proposal.is_passed() {
+                passed += 1;
+            }
+            if proposal.status == ProposalStatus::Vetoed {
+                vetoed += 1;
+            }
+            voter_count_sum += proposal.voter_count as u128;
+        }
+
+        Ok(ParticipationMetrics {
+            proposal_count: count as u64,
+            avg_turnout_bps: (turnout_bps_sum / count) as u64,
+            pass_rate_bps: (passed * 10000 / count) as u64,
+            veto_rate_bps: (vetoed * 10000 / count) as u64,
+            avg_voter_count: (voter_count_sum / count) as u64,
+        })
+    }
+
+    /// The proposal's lifecycle phase right now, derived from its status and the clock
+    pub fn proposal_phase(
+        ctx: Context<ReadProposal>,
+        proposal_id: u64,
+    ) -> Result<ProposalPhaseInfo> {
+        let proposal = &ctx.accounts.proposal;
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+
+        let (phase, slots_until_next_phase) =
+            derive_phase(proposal, &ctx.accounts.governance, Clock::get()?.slot);
+        Ok(ProposalPhaseInfo {
+            phase,
+            slots_until_next_phase,
+        })
+    }
+
+    /// The proposal's computed outcome under the on-chain quorum and pass rules
+    ///
+    /// While an active proposal's voting is still open the result is a live
+    /// projection; once voting has ended `status` is what `finalize_proposal`
+    /// would set. Other statuses are returned as stored.
+    pub fn get_proposal_result(
+        ctx: Context<ReadProposal>,
+        proposal_id: u64,
+    ) -> Result<ProposalResult> {
+        let proposal = &ctx.accounts.proposal;
+        let governance = &ctx.accounts.governance;
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+
+        let passed = evaluate_outcome(proposal, governance)?.is_none();
+        let status = match proposal.status {
+            ProposalStatus::Active if !is_voting_open(proposal, Clock::get()?.slot) => {
+                if passed {
+                    ProposalStatus::Succeeded
+                } else {
+                    ProposalStatus::Defeated
+                }
+            }
+            status => status,
+        };
+
+        Ok(ProposalResult {
+            quorum_reached: quorum_reached(proposal, governance)?,
+            passed,
+            total_votes: quorum_turnout(proposal, governance)?,
+            quorum_votes: quorum_votes(proposal, governance)?,
+            status,
+        })
+    }
+
+    /// Whether `execute_proposal` would currently succeed, with each of its
+    /// guards reported separately instead of failing on the first one
+    ///
+    /// Read-only: the result is returned as instruction return data, so it can
+    /// be read by simulating the transaction. The execution condition oracle
+    /// is not consulted since its account is not passed here.
+    pub fn check_executable(
+        ctx: Context<ReadProposal>,
+        proposal_id: u64,
+    ) -> Result<ExecutableStatus> {
+        let proposal = &ctx.accounts.proposal;
+        let governance = &ctx.accounts.governance;
+        let slot = Clock::get()?.slot;
+        require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+
+        let succeeded = proposal.status == ProposalStatus::Succeeded && !proposal.is_ratification;
+        let in_order = matches!(
+            proposal.finalize_order,
+            Some(order) if order <= governance.execution_sequence
+        );
+        let executable_at = proposal
+            .queued_at_slot
+            .checked_add(governance.execution_delay)
+            .ok_or(GovernanceError::SlotOverflow)?;
+        let timelock_elapsed = succeeded && slot >= executable_at;
+        let staleness = governance.snapshot_staleness_slots;
+        let quorum_holds =
+            if staleness > 0 && slot.saturating_sub(proposal.snapshot_slot) > staleness {
+                quorum_reached_with(proposal, governance, governance.effective_total_power())?
+            } else {
+                quorum_reached(proposal, governance)?
+            };
+        let passed = evaluate_outcome(proposal, governance)?.is_none();
+        let target_allowed = match &proposal.execution_target {
+            Some(target) => governance.is_execution_target_allowed(target),
+            None => true,
+        };
+
+        Ok(ExecutableStatus {
+            executable: !governance.is_paused
+                && succeeded
+                && !proposal.is_frozen
+                && in_order
+                && timelock_elapsed
+                && quorum_holds
+                && passed
+                && target_allowed,
+            paused: governance.is_paused,
+            succeeded,
+            frozen: proposal.is_frozen,
+            in_order,
+            timelock_elapsed,
+            quorum_holds,
+            passed,
+            target_allowed,
+            executable_at,
+        })
+    }
+
+    /// A voter's power, registration and participation in one read
+    ///
+    /// Read-only: the summary is returned as instruction return data for
+    /// transaction simulation, saving clients a scan of every vote record.
+    pub fn voter_summary(ctx: Context<ReadVoter>, voter: Pubkey) -> Result<VoterSummary> {
+        let voter_account = &ctx.accounts.voter_account;
+        require_keys_eq!(
+            voter_account.voter,
+            voter,
+            GovernanceError::InvalidVoterAccount
+        );
+
+        Ok(VoterSummary {
+            voter,
+            voting_power: voter_account.voting_power,
+            delegated_power: voter_account.delegated_power,
+            delegate: voter_account.delegate,
+            registered_at: voter_account.registered_at,
+            votes_cast: voter_account.total_votes_cast,
+            last_voted_slot: voter_account.last_vote_slot,
+            proposals_eligible: voter_account.proposals_eligible(&ctx.accounts.governance),
+            locked_until_slot: voter_account.locked_until_slot,
+        })
+    }
+
+    /// A voter's cumulative participation statistics
+    pub fn voter_stats(ctx: Context<ReadVoter>) -> Result<VoterStats> {
+        let voter_account = &ctx.accounts.voter_account;
+        Ok(VoterStats {
+            voter: voter_account.voter,
+            total_votes_cast: voter_account.total_votes_cast,
+            proposals_voted_for: voter_account.proposals_voted_for,
+            proposals_voted_against: voter_account.proposals_voted_against,
+            last_vote_slot: voter_account.last_vote_slot,
+            proposals_eligible: voter_account.proposals_eligible(&ctx.accounts.governance),
+        })
+    }
+
+    /// Set a voter's remaining quadratic-voting credit budget
+    pub fn set_credit_budget(ctx: Context<SetCreditBudget>, credit_budget: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let voter_account = &mut ctx.accounts.voter_account;
+        let governance = &mut ctx.accounts.governance;
+        governance.total_credit_budget = governance
+            .total_credit_budget
+            .saturating_sub(voter_account.credit_budget)
+            .checked_add(credit_budget)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        voter_account.credit_budget = credit_budget;
+
+        emit!(CreditBudgetSet {
+            voter: voter_account.voter,
+            credit_budget,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Credit budget set to {}", credit_budget);
+        Ok(())
+    }
+
+    /// Halt new proposal submissions while in-flight proposals keep running
+    pub fn pause_submissions(ctx: Context<UpdateGovernance>) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        set_submission_paused(&mut ctx.accounts.governance, true)
+    }
+
+    /// Resume proposal submissions
+    pub fn unpause_submissions(ctx: Context<UpdateGovernance>) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        set_submission_paused(&mut ctx.accounts.governance, false)
+    }
+
+    /// Halt submission, voting and execution during an incident
+    pub fn pause(ctx: Context<UpdateGovernance>) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        set_governance_paused(&mut ctx.accounts.governance, true)
+    }
+
+    /// Resume governance activity
+    pub fn unpause(ctx: Context<UpdateGovernance>) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        set_governance_paused(&mut ctx.accounts.governance, false)
+    }
+
+    /// Configure per-dimension EPI weights and floors
+    ///
+    /// Weights are relative; the composite is normalised by the weights of the
+    /// dimensions a proposal actually provides. A floor of zero disables it.
+    pub fn update_epi_dimensions(
+        ctx: Context<UpdateGovernance>,
+        weights: [u64; MAX_EPI_DIMENSIONS],
+        floors: [u64; MAX_EPI_DIMENSIONS],
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            floors.iter().all(|floor| *floor <= MAX_EPI_SCORE),
+            GovernanceError::InvalidScore
+        );
+
+        let governance = &mut ctx.accounts.governance;
+        governance.dimension_weights = weights;
+        governance.dimension_floors = floors;
+
+        emit!(EpiDimensionsUpdated {
+            weights,
+            floors,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("EPI dimensions updated");
+        Ok(())
+    }
+
+    /// Update how many failed executions a proposal tolerates before `ExecutionFailed`
+    pub fn update_max_execution_attempts(
+        ctx: Context<UpdateGovernance>,
+        max_execution_attempts: u8,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            max_execution_attempts > 0,
+            GovernanceError::InvalidExecutionAttempts
+        );
+        ctx.accounts.governance.max_execution_attempts = max_execution_attempts;
+
+        msg!("Max execution attempts updated: {}", max_execution_attempts);
+        Ok(())
+    }
+
+    /// Update how many guardian approvals activate a below-threshold proposal
+    ///
+    /// Zero disables overrides, so below-threshold submissions are rejected.
+    pub fn update_epi_override_threshold(
+        ctx: Context<UpdateGovernance>,
+        epi_override_threshold: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.epi_override_threshold = epi_override_threshold;
+
+        msg!("EPI override threshold updated: {}", epi_override_threshold);
+        Ok(())
+    }
+
+    /// Update the maximum number of options a proposal may offer
+    pub fn update_max_options(ctx: Context<UpdateGovernance>, max_options: u8) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(max_options >= 2, GovernanceError::InvalidOptionCount);
+        require!(
+            max_options <= MAX_PROPOSAL_OPTIONS,
+            GovernanceError::TooManyOptions
+        );
+        ctx.accounts.governance.max_options = max_options;
+
+        msg!("Max proposal options updated: {}", max_options);
+        Ok(())
+    }
+
+    /// Configure how fast guardians' `recent_veto_score` decays
+    ///
+    /// Every `window` slots the score drops by `per_window`; a zero window
+    /// disables decay.
+    pub fn update_veto_decay(
+        ctx: Context<UpdateGovernance>,
+        window: u64,
+        per_window: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        governance.veto_decay_window = window;
+        governance.veto_decay_per_window = per_window;
+
+        msg!("Veto decay set to {} per {} slots", per_window, window);
+        Ok(())
+    }
+
+    /// Configure late-vote extensions; a `window` of 0 disables them
+    pub fn update_vote_extension(
+        ctx: Context<UpdateGovernance>,
+        window: u64,
+        period: u64,
+        max_extensions: u8,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        governance.extension_window = window;
+        governance.extension_period = period;
+        governance.max_extensions = max_extensions;
+
+        msg!(
+            "Vote extension set to {} slots within {} of the deadline, at most {} times",
+            period,
+            window,
+            max_extensions
+        );
+        Ok(())
+    }
+
+    /// A guardian's recent veto score with decay applied up to the current slot
+    pub fn guardian_veto_score(ctx: Context<ReadGuardian>) -> Result<u64> {
+        let slot = Clock::get()?.slot;
+        Ok(ctx
+            .accounts
+            .guardian_account
+            .decayed_veto_score(&ctx.accounts.governance, slot)
+            .0)
+    }
+
+    /// Allow proposals to target `program` for execution
+    pub fn add_execution_target(ctx: Context<UpdateGovernance>, program: Pubkey) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            !governance.execution_target_allowlist.contains(&program),
+            GovernanceError::ExecutionTargetAlreadyAllowed
+        );
+        require!(
+            governance.execution_target_allowlist.len() < MAX_EXECUTION_TARGETS,
+            GovernanceError::ExecutionAllowlistFull
+        );
+        governance.execution_target_allowlist.push(program);
+
+        emit!(ExecutionTargetUpdated {
+            program,
+            allowed: true,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Execution target allowed: {}", program);
+        Ok(())
+    }
+
+    /// Remove `program` from the execution allowlist
+    pub fn remove_execution_target(ctx: Context<UpdateGovernance>, program: Pubkey) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        let index = governance
+            .execution_target_allowlist
+            .iter()
+            .position(|target| *target == program)
+            .ok_or(GovernanceError::ExecutionTargetNotAllowed)?;
+        governance.execution_target_allowlist.remove(index);
+
+        emit!(ExecutionTargetUpdated {
+            program,
+            allowed: false,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Execution target removed: {}", program);
+        Ok(())
+    }
+
+    /// Update the oldest proposal document schema version accepted at submission
+    pub fn update_min_document_schema_version(
+        ctx: Context<UpdateGovernance>,
+        min_version: u16,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.min_document_schema_version = min_version;
+
+        msg!("Min document schema version updated: {}", min_version);
+        Ok(())
+    }
+
+    /// Update how many proposals per category may be active at once (0 = no cap)
+    pub fn update_max_active_per_category(
+        ctx: Context<UpdateGovernance>,
+        max_active: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.max_active_per_category = max_active;
+
+        msg!("Max active proposals per category updated: {}", max_active);
+        Ok(())
+    }
+
+    /// Restrict proposal submission to holders of `mint`; `None` lifts the gate
+    pub fn set_proposal_gate_mint(
+        ctx: Context<UpdateGovernance>,
+        mint: Option<Pubkey>,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.proposal_gate_mint = mint;
+
+        msg!("Proposal gate mint updated: {:?}", mint);
+        Ok(())
+    }
+
+    /// Update the slots a proposer must wait between submissions (0 = no limit)
+    pub fn update_proposal_cooldown(
+        ctx: Context<UpdateGovernance>,
+        proposal_cooldown: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.proposal_cooldown = proposal_cooldown;
+
+        msg!("Proposal cooldown updated: {} slots", proposal_cooldown);
+        Ok(())
+    }
+
+    /// Update how far in the future (in slots) a proposal may schedule its voting start
+    pub fn update_max_start_delay(
+        ctx: Context<UpdateGovernance>,
+        max_start_delay: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.max_start_delay = max_start_delay;
+
+        msg!("Max start delay updated: {} slots", max_start_delay);
+        Ok(())
+    }
+
+    /// Update how long (in slots) after voting ends a terminal proposal must
+    /// wait before it can be closed
+    pub fn update_cleanup_delay(ctx: Context<UpdateGovernance>, cleanup_delay: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.cleanup_delay = cleanup_delay;
+
+        msg!("Cleanup delay updated: {} slots", cleanup_delay);
+        Ok(())
+    }
+
+    /// Update the age (in slots) after which a proposal's power snapshot is stale
+    /// and execution must reach quorum against both electorates (0 = never)
+    pub fn update_snapshot_staleness(
+        ctx: Context<UpdateGovernance>,
+        staleness_slots: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.snapshot_staleness_slots = staleness_slots;
+
+        msg!("Snapshot staleness updated: {} slots", staleness_slots);
+        Ok(())
+    }
+
+    /// Set who may execute proposals: per category when `category` is given,
+    /// otherwise the global fallback. `None` clears the requirement.
+    pub fn set_execution_authority(
+        ctx: Context<UpdateGovernance>,
+        category: Option<u8>,
+        execution_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        match category {
+            Some(category) => {
+                require!(
+                    (category as usize) < MAX_CATEGORIES,
+                    GovernanceError::InvalidCategory
+                );
+                governance.category_execution_authorities[category as usize] = execution_authority;
+            }
+            None => governance.execution_authority = execution_authority,
+        }
+
+        emit!(ExecutionAuthorityUpdated {
+            category,
+            execution_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Execution authority updated for category {:?}", category);
+        Ok(())
+    }
+
+    /// Update the global quorum, in basis points of the power snapshot
+    /// (5100 = 51%)
+    pub fn update_quorum(ctx: Context<UpdateGovernance>, quorum_bps: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(quorum_bps <= 10000, GovernanceError::InvalidQuorum);
+        ctx.accounts.governance.quorum_bps = quorum_bps;
+
+        msg!("Quorum updated: {} bps", quorum_bps);
+        Ok(())
+    }
+
+    /// Configure guardian emergency proposals; a voting period of 0 disables them
+    pub fn update_emergency_config(
+        ctx: Context<UpdateGovernance>,
+        emergency_voting_period: u64,
+        emergency_quorum: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            emergency_voting_period <= governance.voting_period,
+            GovernanceError::InvalidVotingPeriod
+        );
+        require!(emergency_quorum <= 10000, GovernanceError::InvalidQuorum);
+        governance.emergency_voting_period = emergency_voting_period;
+        governance.emergency_quorum = emergency_quorum;
+
+        msg!(
+            "Emergency config updated: period={} quorum={} bps",
+            emergency_voting_period,
+            emergency_quorum
+        );
+        Ok(())
+    }
+
+    /// Set a category's quorum in basis points; 0 reverts it to the global quorum
+    pub fn update_category_quorum(
+        ctx: Context<UpdateGovernance>,
+        category: u8,
+        quorum: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            (category as usize) < MAX_CATEGORIES,
+            GovernanceError::InvalidCategory
+        );
+        require!(quorum <= 10000, GovernanceError::InvalidQuorum);
+        let governance = &mut ctx.accounts.governance;
+        governance.category_quorums[category as usize] = quorum;
+
+        emit!(CategoryQuorumUpdated {
+            category,
+            quorum,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Quorum for category {} set to {} bps", category, quorum);
+        Ok(())
+    }
+
+    /// Update the guardian veto weight required against a unanimous proposal
+    pub fn update_base_veto_threshold(
+        ctx: Context<UpdateGovernance>,
+        base_veto_threshold: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            base_veto_threshold > 0 && base_veto_threshold <= governance.total_guardian_weight,
+            GovernanceError::InvalidVetoThreshold
+        );
+        governance.base_veto_threshold = base_veto_threshold;
+
+        msg!("Base veto threshold updated: {}", base_veto_threshold);
+        Ok(())
+    }
+
+    /// Update the guardian veto weight that must accumulate before a proposal is vetoed
+    pub fn update_veto_threshold(
+        ctx: Context<UpdateGovernance>,
+        veto_threshold: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            veto_threshold > 0 && veto_threshold <= governance.total_guardian_weight,
+            GovernanceError::InvalidVetoThreshold
+        );
+        governance.veto_threshold = veto_threshold;
+
+        msg!(
+            "Veto threshold updated: {} of {} guardian weight",
+            veto_threshold,
+            governance.total_guardian_weight
+        );
+        Ok(())
+    }
+
+    /// Update the maximum number of active guardians; it cannot drop below
+    /// the current count
+    pub fn update_max_guardians(ctx: Context<UpdateGovernance>, max_guardians: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            max_guardians > 0 && max_guardians >= governance.guardian_count,
+            GovernanceError::InvalidGuardianCap
+        );
+        governance.max_guardians = max_guardians;
+
+        msg!("Max guardians updated: {}", max_guardians);
+        Ok(())
+    }
+
+    /// Update the length (in seconds) of guardian terms granted from now on;
+    /// 0 appoints guardians without expiry
+    pub fn update_guardian_term_length(
+        ctx: Context<UpdateGovernance>,
+        guardian_term_length: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.guardian_term_length = guardian_term_length;
+
+        msg!(
+            "Guardian term length updated: {} seconds",
+            guardian_term_length
+        );
+        Ok(())
+    }
+
+    /// Update the number of distinct voters a proposal needs to pass (0 = any)
+    pub fn update_min_voters(ctx: Context<UpdateGovernance>, min_voters: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.min_voters = min_voters;
+
+        msg!("Min voters updated: {}", min_voters);
+        Ok(())
+    }
+
+    /// Update the absolute for-minus-against margin a proposal needs to pass
+    pub fn update_min_margin(ctx: Context<UpdateGovernance>, min_margin: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.min_margin = min_margin;
+
+        msg!("Min margin updated: {}", min_margin);
+        Ok(())
+    }
+
+    /// Update the share of the power snapshot (basis points) that `votes_for`
+    /// must exceed to override a veto; 0 disables overrides
+    pub fn update_override_threshold(
+        ctx: Context<UpdateGovernance>,
+        override_threshold: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            override_threshold <= 10000,
+            GovernanceError::InvalidOverrideThreshold
+        );
+        ctx.accounts.governance.override_threshold = override_threshold;
+
+        msg!(
+            "Veto override threshold updated: {} bps",
+            override_threshold
+        );
+        Ok(())
+    }
+
+    /// Enable or disable sortition tie-breaking
+    pub fn update_sortition_tiebreak(ctx: Context<UpdateGovernance>, enabled: bool) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.sortition_tiebreak = enabled;
+
+        msg!("Sortition tie-break enabled: {}", enabled);
+        Ok(())
+    }
+
+    /// Decide whether an exact for/against tie passes (`true`) or is defeated
+    pub fn update_tie_breaks_for(
+        ctx: Context<UpdateGovernance>,
+        tie_breaks_for: bool,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.tie_breaks_for = tie_breaks_for;
+
+        msg!("Ties pass: {}", tie_breaks_for);
+        Ok(())
+    }
+
+    /// Enable or disable finalizing and executing decided proposals before
+    /// their voting period ends
+    ///
+    /// While enabled, votes cannot be changed or withdrawn, so a decided
+    /// outcome stays decided.
+    pub fn update_allow_early_execution(
+        ctx: Context<UpdateGovernance>,
+        enabled: bool,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.allow_early_execution = enabled;
+
+        msg!("Early execution enabled: {}", enabled);
+        Ok(())
+    }
+
+    /// Update the voting period (in slots) for future proposals
+    pub fn update_voting_period(ctx: Context<UpdateGovernance>, new_period: u64) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            new_period <= MAX_VOTING_PERIOD,
+            GovernanceError::InvalidVotingPeriod
+        );
+
+        let governance = &mut ctx.accounts.governance;
+        let old_period = governance.voting_period;
+        governance.voting_period = new_period;
+
+        emit!(VotingPeriodUpdated {
+            old_period,
+            new_period,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Voting period updated: {} -> {}", old_period, new_period);
+        Ok(())
+    }
+
+    /// Update the minimum voting power required to submit (and keep) a proposal
+    pub fn update_min_proposal_power(
+        ctx: Context<UpdateGovernance>,
+        new_min_power: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        let old_min_power = governance.min_proposal_power;
+        governance.min_proposal_power = new_min_power;
+
+        emit!(MinProposalPowerUpdated {
+            old_min_power,
+            new_min_power,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Min proposal power updated: {} -> {}",
+            old_min_power,
+            new_min_power
+        );
+        Ok(())
+    }
+
+    /// Update the minimum registered voting power needed to cast a vote
+    pub fn update_min_vote_power(
+        ctx: Context<UpdateGovernance>,
+        min_vote_power: u64,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        ctx.accounts.governance.min_vote_power = min_vote_power;
+
+        msg!("Min vote power updated: {}", min_vote_power);
+        Ok(())
+    }
+
+    /// Nominate a new governance authority; takes effect once they accept.
+    /// Nominating again replaces the pending nominee.
+    pub fn propose_authority_transfer(
+        ctx: Context<UpdateGovernance>,
+        new_authority: Pubkey,
+    ) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        governance.pending_authority = Some(new_authority);
+
+        emit!(AuthorityTransferProposed {
+            current_authority: governance.authority,
+            pending_authority: new_authority,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority transfer proposed to {}", new_authority);
+        Ok(())
+    }
+
+    /// Accept a pending authority nomination, signed by the nominee
+    pub fn accept_authority_transfer(ctx: Context<AcceptAuthorityTransfer>) -> Result<()> {
+        let governance = &mut ctx.accounts.governance;
+        let pending = governance
+            .pending_authority
+            .ok_or(GovernanceError::NoPendingAuthority)?;
+        require_keys_eq!(
+            ctx.accounts.new_authority.key(),
+            pending,
+            GovernanceError::Unauthorized
+        );
+
+        let old_authority = governance.authority;
+        governance.authority = pending;
+        governance.pending_authority = None;
+
+        emit!(AuthorityTransferred {
+            old_authority,
+            new_authority: pending,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority transferred: {} -> {}", old_authority, pending);
+        Ok(())
+    }
+
+    /// Add a member to the authority council
+    pub fn add_authority_signer(ctx: Context<UpdateGovernance>, signer: Pubkey) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        require!(
+            signer != Pubkey::default(),
+            GovernanceError::InvalidAuthoritySigner
+        );
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            !governance.is_authority_signer(&signer),
+            GovernanceError::InvalidAuthoritySigner
+        );
+        let slot = governance
+            .authority_signers
+            .iter_mut()
+            .find(|member| **member == Pubkey::default())
+            .ok_or(GovernanceError::AuthorityCouncilFull)?;
+        *slot = signer;
+
+        emit!(AuthorityCouncilUpdated {
+            signer,
+            added: true,
+            signer_count: governance.authority_signer_count(),
+            threshold: governance.authority_threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority signer added: {}", signer);
+        Ok(())
+    }
+
+    /// Remove a member from the authority council; fails if the remaining
+    /// members could no longer meet the threshold
+    pub fn remove_authority_signer(ctx: Context<UpdateGovernance>, signer: Pubkey) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        let slot = governance
+            .authority_signers
+            .iter_mut()
+            .find(|member| **member == signer && signer != Pubkey::default())
+            .ok_or(GovernanceError::InvalidAuthoritySigner)?;
+        *slot = Pubkey::default();
+        require!(
+            governance.authority_threshold as usize <= governance.authority_signer_count(),
+            GovernanceError::InvalidAuthorityThreshold
+        );
+
+        emit!(AuthorityCouncilUpdated {
+            signer,
+            added: false,
+            signer_count: governance.authority_signer_count(),
+            threshold: governance.authority_threshold,
+            timestamp: Clock::get()?.unix_timestamp,
+        });
+
+        msg!("Authority signer removed: {}", signer);
+        Ok(())
+    }
+
+    /// Set how many council members must approve privileged instructions;
+    /// 0 leaves the authority acting alone
+    pub fn update_authority_threshold(ctx: Context<UpdateGovernance>, threshold: u8) -> Result<()> {
+        require_council_approval(
+            &ctx.accounts.governance,
+            ctx.accounts.authority.key,
+            ctx.remaining_accounts,
+        )?;
+        let governance = &mut ctx.accounts.governance;
+        require!(
+            threshold as usize <= governance.authority_signer_count(),
+            GovernanceError::InvalidAuthorityThreshold
+        );
+        governance.authority_threshold = threshold;
+        msg!("Authority threshold updated to: {}", threshold);
+        Ok(())
+    }
+}
+
+// ============ Helpers ============
+
+/// Require `authority_threshold` distinct council members to approve a
+/// privileged instruction
+///
+/// The authority counts if it is itself a member; other members co-sign by
+/// being passed as signer accounts in `remaining_accounts`. A threshold of 0
+/// leaves the authority acting alone.
+fn require_council_approval(
+    governance: &Governance,
+    authority: &Pubkey,
+    remaining_accounts: &[AccountInfo],
+) -> Result<()> {
+    if governance.authority_threshold == 0 {
+        return Ok(());
+    }
+
+    let mut approvals: Vec<Pubkey> = Vec::new();
+    let co_signers = remaining_accounts
+        .iter()
+        .filter(|info| info.is_signer)
+        .map(|info| info.key());
+    for key in std::iter::once(*authority).chain(co_signers) {
+        if governance.is_authority_signer(&key) && !approvals.contains(&key) {
+            approvals.push(key);
+        }
+    }
+    require!(
+        approvals.len() >= governance.authority_threshold as usize,
+        GovernanceError::AuthorityQuorumNotMet
+    );
+    Ok(())
+}
+
+/// Move `amount` lamports from the treasury to `recipient`, never dipping
+/// into the treasury's rent-exempt reserve
+///
+/// The treasury is owned by this program, so its balance is debited directly
+/// rather than through a signed system transfer.
+fn disburse_from_treasury(
+    treasury: &Account<Treasury>,
+    recipient: &AccountInfo,
+    amount: u64,
+) -> Result<()> {
+    let treasury_info = treasury.to_account_info();
+    let reserve = Rent::get()?.minimum_balance(treasury_info.data_len());
+    let available = treasury_info.lamports().saturating_sub(reserve);
+    require!(amount <= available, GovernanceError::InsufficientTreasury);
+
+    **treasury_info.try_borrow_mut_lamports()? -= amount;
+    let recipient_balance = recipient.lamports();
+    **recipient.try_borrow_mut_lamports()? = recipient_balance
+        .checked_add(amount)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Create the `ThoughtHashRegistry` PDA claiming `thought_hash` for `proposal_id`
+///
+/// The registry is created here rather than with `init` so that a reused hash
+/// reports `DuplicateThoughtHash` instead of a system error.
+fn register_thought_hash<'info>(
+    registry: &UncheckedAccount<'info>,
+    bump: u8,
+    thought_hash: &[u8; 32],
+    proposal_id: u64,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let registry_info = registry.to_account_info();
+    require!(
+        registry_info.lamports() == 0,
+        GovernanceError::DuplicateThoughtHash
+    );
+    let space = 8 + ThoughtHashRegistry::INIT_SPACE;
+    anchor_lang::system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::CreateAccount {
+                from: payer.to_account_info(),
+                to: registry_info.clone(),
+            },
+            &[&[b"thought_hash", thought_hash.as_ref(), &[bump]]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    )?;
+    ThoughtHashRegistry { proposal_id, bump }
+        .try_serialize(&mut &mut registry_info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+/// Invoke `target` with `data`, signing as the governance PDA
+///
+/// Instruction accounts are taken from `remaining_accounts` in order, skipping
+/// the target program's own account. A failing callee aborts the transaction.
+fn invoke_governance_payload<'info>(
+    target: Pubkey,
+    data: &[u8],
+    governance: &Account<'info, Governance>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let governance_key = governance.key();
+    let accounts = remaining_accounts
+        .iter()
+        .filter(|account| account.key() != target)
+        .map(|account| AccountMeta {
+            pubkey: account.key(),
+            is_signer: account.is_signer || account.key() == governance_key,
+            is_writable: account.is_writable,
+        })
+        .collect();
+    let instruction = Instruction {
+        program_id: target,
+        accounts,
+        data: data.to_vec(),
+    };
+
+    let mut account_infos = remaining_accounts.to_vec();
+    account_infos.push(governance.to_account_info());
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[b"governance", &[governance.bump]]],
+    )?;
+    Ok(())
+}
+
+/// Whether turnout meets the category's quorum (basis points) of the voting
+/// power snapshotted when the proposal was created; emergency proposals use
+/// `emergency_quorum` instead
+pub fn quorum_reached(proposal: &Proposal, governance: &Governance) -> Result<bool> {
+    quorum_reached_with(proposal, governance, proposal.total_power_snapshot)
+}
+
+/// Whether turnout meets quorum measured against an explicit electorate size
+pub fn quorum_reached_with(
+    proposal: &Proposal,
+    governance: &Governance,
+    total_power: u64,
+) -> Result<bool> {
+    Ok(
+        quorum_turnout(proposal, governance)?
+            >= quorum_votes_of(governance, proposal, total_power)?,
+    )
+}
+
+/// Votes that count toward quorum: every option plus abstentions when
+/// `abstain_counts_for_quorum` is set, otherwise the options alone
+pub fn quorum_turnout(proposal: &Proposal, governance: &Governance) -> Result<u64> {
+    if governance.abstain_counts_for_quorum {
+        return total_votes(proposal);
+    }
+    u64::try_from(proposal.option_turnout()).map_err(|_| GovernanceError::ArithmeticOverflow.into())
+}
+
+/// Votes required for quorum on `proposal`
+pub fn quorum_votes(proposal: &Proposal, governance: &Governance) -> Result<u64> {
+    quorum_votes_of(governance, proposal, proposal.total_power_snapshot)
+}
+
+fn quorum_votes_of(governance: &Governance, proposal: &Proposal, total_power: u64) -> Result<u64> {
+    // Widen before multiplying so large electorates cannot overflow
+    let quorum = if proposal.is_emergency {
+        governance.emergency_quorum
+    } else {
+        governance.quorum_bps_for(proposal.category)
+    };
+    let required = total_power as u128 * quorum as u128 / 10000;
+    u64::try_from(required).map_err(|_| GovernanceError::ArithmeticOverflow.into())
+}
+
+/// Sum of all tally buckets
+pub fn total_votes(proposal: &Proposal) -> Result<u64> {
+    u64::try_from(proposal.option_turnout() + proposal.votes_abstain as u128)
+        .map_err(|_| GovernanceError::ArithmeticOverflow.into())
+}
+
+/// Shared pass/fail evaluation for a proposal whose voting has ended.
+///
+/// A proposal passes when quorum is reached, at least `min_voters` distinct
+/// voters took part, a winning option emerges by
+/// plurality (see `winning_option`), and its lead over the runner-up is at
+/// least `min_margin`. A binary proposal won by option 0 is defeated by votes
+/// against; an undecided tie is reported as `Tied`.
+/// Returns why it was defeated, or `None` if it passed.
+pub fn evaluate_outcome(
+    proposal: &Proposal,
+    governance: &Governance,
+) -> Result<Option<DefeatReason>> {
+    if !quorum_reached(proposal, governance)? {
+        return Ok(Some(DefeatReason::QuorumNotReached));
+    }
+    if proposal.voter_count < governance.min_voters {
+        return Ok(Some(DefeatReason::TurnoutNotReached));
+    }
+    let winner = match winning_option(proposal, governance) {
+        Some(winner) => winner,
+        None => return Ok(Some(DefeatReason::Tied)),
+    };
+    if proposal.is_binary() && winner == 0 {
+        return Ok(Some(DefeatReason::VotesAgainst));
+    }
+    let runner_up = (0..proposal.num_options)
+        .filter(|&option| option != winner)
+        .map(|option| proposal.option_tally(option))
+        .max()
+        .unwrap_or(0);
+    let margin = proposal.option_tally(winner).saturating_sub(runner_up);
+    if margin < governance.min_margin {
+        return Ok(Some(DefeatReason::MarginTooNarrow));
+    }
+    Ok(None)
+}
+
+/// Option with the most votes. A tie goes to `for` on a binary proposal with
+/// `tie_breaks_for` set, otherwise to the sortition winner if one was drawn.
+pub fn winning_option(proposal: &Proposal, governance: &Governance) -> Option<u8> {
+    let leaders = proposal.leading_options();
+    if let [winner] = leaders[..] {
+        return Some(winner);
+    }
+    if proposal.is_binary() && governance.tie_breaks_for {
+        return Some(1);
+    }
+    proposal
+        .tiebreak_winner
+        .filter(|winner| leaders.contains(winner))
+}
+
+/// Whether a binary proposal still in its voting period can no longer fail.
+///
+/// Quorum and `min_voters` must already be met, and `votes_for` must lead by
+/// more than `min_margin` over `votes_against` plus everything still castable:
+/// live registered power not yet voted (so registrations after the snapshot
+/// count) and the unspent `total_credit_budget`. Voters who voted keep their
+/// power locked, and `allow_early_execution` blocks changing or withdrawing
+/// a vote, so tallies only grow. Quadratic weights and credit purchases are
+/// counted linearly, which only overstates what could still be cast.
+pub fn outcome_decided(proposal: &Proposal, governance: &Governance) -> Result<bool> {
+    if !proposal.is_binary()
+        || !quorum_reached(proposal, governance)?
+        || proposal.voter_count < governance.min_voters
+    {
+        return Ok(false);
+    }
+    let power_votes = total_votes(proposal)?.saturating_sub(proposal.credit_votes);
+    let unvoted = governance
+        .effective_total_power()
+        .saturating_sub(power_votes);
+    let worst_case_against =
+        proposal.votes_against as u128 + unvoted as u128 + governance.total_credit_budget as u128;
+    let votes_for = proposal.votes_for as u128;
+    Ok(votes_for > worst_case_against
+        && votes_for - worst_case_against >= governance.min_margin as u128)
+}
+
+/// End voting at the current slot for a proposal finalized early
+fn close_voting_early(proposal: &mut Proposal, clock: &Clock) {
+    proposal.end_slot = clock.slot;
+    proposal.end_ts = clock.unix_timestamp;
+}
+
+/// Settle an active proposal as `Succeeded` or `Defeated`, queueing it for
+/// execution on success, and emit the finalization events
+fn apply_outcome(
+    proposal: &mut Proposal,
+    governance: &mut Governance,
+    clock: &Clock,
+) -> Result<Option<DefeatReason>> {
+    require!(
+        !proposal.is_ranked || proposal.ranked_tally_complete,
+        GovernanceError::RankedTallyIncomplete
+    );
+    let defeat_reason = evaluate_outcome(proposal, governance)?;
+    proposal.defeat_reason = defeat_reason;
+    proposal.status = match defeat_reason {
+        None => ProposalStatus::Succeeded,
+        Some(_) => ProposalStatus::Defeated,
+    };
+    if defeat_reason.is_none() {
+        proposal.winning_option = winning_option(proposal, governance);
+        proposal.queued_at_slot = clock.slot;
+        governance.enqueue_for_execution(proposal)?;
+    }
+    governance.release_active_slot(proposal);
+
+    if let Some(reason) = defeat_reason {
+        emit!(ProposalDefeated {
+            proposal_id: proposal.id,
+            reason,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(ProposalFinalized {
+        proposal_id: proposal.id,
+        status: proposal.status,
+        vote_hash_chain: proposal.vote_hash_chain,
+        timestamp: clock.unix_timestamp,
+    });
+    Ok(defeat_reason)
+}
+
+/// Whether support for `proposal` exceeds `override_threshold` basis points
+/// of its power snapshot
+pub fn override_threshold_met(proposal: &Proposal, governance: &Governance) -> bool {
+    proposal.support_votes() as u128 * 10000
+        > governance.override_threshold as u128 * proposal.total_power_snapshot as u128
+}
+
+/// Whether `override_veto` would still accept `proposal`
+pub fn veto_overridable(proposal: &Proposal, governance: &Governance) -> bool {
+    proposal.status == ProposalStatus::Vetoed
+        && !proposal.veto_overridden
+        && governance.override_threshold > 0
+        && override_threshold_met(proposal, governance)
+}
+
+/// Guardian veto weight needed to veto `proposal`:
+/// `ceil(base_veto_threshold * support_votes / total_votes)`, at least 1.
+///
+/// A landslide needs close to the full base threshold while a narrow pass
+/// needs proportionally less; a proposal nobody has voted for needs just one.
+pub fn required_veto_weight(proposal: &Proposal, base_veto_threshold: u64) -> u64 {
+    let total_votes = proposal.option_turnout() + proposal.votes_abstain as u128;
+    if total_votes == 0 {
+        return 1;
+    }
+    let numerator = base_veto_threshold as u128 * proposal.support_votes() as u128;
+    let mut scaled = numerator / total_votes;
+    if scaled * total_votes < numerator {
+        scaled += 1;
+    }
+    (scaled as u64).max(1)
+}
+
+/// Extend voting by `extension_period` when a vote cast within
+/// `extension_window` slots of `end_slot` changes which option leads.
+///
+/// `leaders_before` is `leading_options` before the vote was applied. Moving
+/// into or out of a tie counts as a change, so a vote that nearly flips the
+/// outcome also extends. Extensions stop after `max_extensions`.
+fn extend_if_lead_changed(
+    proposal: &mut Proposal,
+    governance: &Governance,
+    leaders_before: &[u8],
+    slot: u64,
+) -> Result<()> {
+    if governance.extension_window == 0
+        || proposal.extension_count >= governance.max_extensions
+        || proposal.end_slot.saturating_sub(slot) >= governance.extension_window
+    {
+        return Ok(());
+    }
+    if proposal.leading_options() == leaders_before {
+        return Ok(());
+    }
+
+    proposal.end_slot = proposal
+        .end_slot
+        .checked_add(governance.extension_period)
+        .ok_or(GovernanceError::SlotOverflow)?;
+    proposal.end_ts = slot_timestamp(&Clock::get()?, proposal.end_slot);
+    proposal.extension_count += 1;
+
+    emit!(VotingExtended {
+        proposal_id: proposal.id,
+        end_slot: proposal.end_slot,
+        extension_count: proposal.extension_count,
+        slot,
+    });
+    Ok(())
+}
+
+/// Integer square root, rounded down
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    // Newton's method from ceil(n / 2), which is at least sqrt(n) for n >= 2
+    let mut x = (n >> 1) + (n & 1);
+    let mut y = (x + n / x) >> 1;
+    while y < x {
+        x = y;
+        y = (x + n / x) >> 1;
+    }
+    x
+}
+
+/// Pick one of the tied options uniformly from `seed`
+pub fn select_tied_option(tied: &[u8], seed: &[u8; 32]) -> u8 {
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&seed[..8]);
+    let index = u64::from_le_bytes(word) % tied.len() as u64;
+    tied[index as usize]
+}
+
+/// Hash of the newest entry in the `SlotHashes` sysvar, read directly from
+/// account data since the full sysvar is too large to deserialize on-chain.
+fn most_recent_slot_hash(slot_hashes: &AccountInfo) -> Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    // Layout: u64 entry count, then (u64 slot, [u8; 32] hash) newest first
+    let hash = data.get(16..48).ok_or(GovernanceError::InvalidSlotHashes)?;
+    let mut recent = [0u8; 32];
+    recent.copy_from_slice(hash);
+    Ok(recent)
+}
+
+/// Reject a category that does not exist or already has
+/// `max_active_per_category` proposals in flight
+fn require_category_capacity(governance: &Governance, category: u8) -> Result<()> {
+    require!(
+        (category as usize) < MAX_CATEGORIES,
+        GovernanceError::InvalidCategory
+    );
+    let max_active = governance.max_active_per_category;
+    require!(
+        max_active == 0 || governance.category_active_counts[category as usize] < max_active,
+        GovernanceError::CategoryActiveLimitReached
+    );
+    Ok(())
+}
+
+/// A proposal's scores and category can be corrected while it is active and
+/// nobody has voted on it yet
+fn require_correctable(proposal: &Proposal) -> Result<()> {
+    require!(
+        proposal.status == ProposalStatus::Active,
+        GovernanceError::ProposalNotActive
+    );
+    require!(
+        total_votes(proposal)? == 0,
+        GovernanceError::ProposalHasVotes
+    );
+    Ok(())
+}
+
+/// Proposal-side checks shared by every instruction that casts a new vote:
+/// the proposal is active, unfrozen and inside its voting window
+fn require_votable(proposal: &Proposal, proposal_id: u64, slot: u64) -> Result<()> {
+    require!(
+        proposal.status == ProposalStatus::Active,
+        GovernanceError::ProposalNotActive
+    );
+    require!(proposal.id == proposal_id, GovernanceError::InvalidProposal);
+    require!(
+        slot >= proposal.start_slot,
+        GovernanceError::VotingNotStarted
+    );
+    require!(is_voting_open(proposal, slot), GovernanceError::VotingEnded);
+    require!(!proposal.is_frozen, GovernanceError::ProposalFrozen);
+    Ok(())
+}
+
+/// Record a new vote from `ctx.accounts.voter`; `rankings` is empty unless
+/// the ballot is ranked, in which case `support` is its first choice
+fn cast_ballot(
+    ctx: Context<Vote>,
+    proposal_id: u64,
+    support: u8,
+    rankings: Vec<u8>,
+    reason: String,
+) -> Result<()> {
+    require!(
+        !ctx.accounts.governance.is_paused,
+        GovernanceError::GovernancePaused
+    );
+    let proposal = &mut ctx.accounts.proposal;
+    let vote_record = &mut ctx.accounts.vote_record;
+    let voter_account = &mut ctx.accounts.voter_account;
+    let clock = Clock::get()?;
+
+    require_votable(proposal, proposal_id, clock.slot)?;
+    let vote_support = proposal.vote_support(support)?;
+    require!(
+        reason.len() <= MAX_VOTE_REASON_LEN,
+        GovernanceError::ReasonTooLong
+    );
+    let voting_power = eligible_vote_weight(&ctx.accounts.governance, voter_account)?;
+
+    vote_record.voter = ctx.accounts.voter.key();
+    vote_record.proposal_id = proposal_id;
+    vote_record.support = support;
+    vote_record.voting_power = voting_power;
+    vote_record.timestamp = clock.unix_timestamp;
+    vote_record.reason = reason.clone();
+    vote_record.rankings = rankings;
+    vote_record.counted_option = support;
+    vote_record.bump = ctx.bumps.vote_record;
+    proposal.chain_vote(&vote_record.leaf());
+
+    let leaders_before = proposal.leading_options();
+    add_to_tally(proposal, support, voting_power)?;
+    proposal.record_voter(voting_power)?;
+    extend_if_lead_changed(
+        proposal,
+        &ctx.accounts.governance,
+        &leaders_before,
+        clock.slot,
+    )?;
+    voter_account.record_vote(proposal.stance(support), clock.slot);
+    voter_account.lock_until(proposal.end_slot);
+
+    emit!(voter_account.participation(&ctx.accounts.governance, clock.unix_timestamp));
+    emit!(VoteCast {
+        proposal_id,
+        voter: ctx.accounts.voter.key(),
+        support: vote_support,
+        voting_power,
+        reason,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Vote cast on proposal {}: support={} power={}",
+        proposal_id,
+        support,
+        voting_power
+    );
+    Ok(())
+}
+
+/// A ranked ballot names at least one option, each in range and at most once
+fn require_valid_rankings(proposal: &Proposal, rankings: &[u8]) -> Result<()> {
+    require!(!rankings.is_empty(), GovernanceError::InvalidRankings);
+    let mut ranked: u8 = 0;
+    for &option in rankings {
+        require!(
+            option < proposal.num_options,
+            GovernanceError::InvalidRankings
+        );
+        require!(
+            ranked & (1 << option) == 0,
+            GovernanceError::InvalidRankings
+        );
+        ranked |= 1 << option;
+    }
+    Ok(())
+}
+
+/// Tally weight a voter casts, once they are known to be eligible: their
+/// power is not delegated away and meets `min_vote_power`
+fn eligible_vote_weight(governance: &Governance, voter_account: &VoterAccount) -> Result<u64> {
+    require!(
+        voter_account.delegate.is_none(),
+        GovernanceError::VotingPowerDelegated
+    );
+    let raw_power = voter_account.effective_voting_power()?;
+    require!(
+        raw_power >= governance.min_vote_power,
+        GovernanceError::InsufficientVotingPower
+    );
+    let voting_power = governance.vote_weight(raw_power);
+    require!(voting_power > 0, GovernanceError::NoVotingPower);
+    Ok(voting_power)
+}
+
+/// Single source of truth for the voting deadline. `end_slot` is inclusive:
+/// a vote cast in `end_slot` counts, and anything that needs voting to be
+/// over (finalize, execute, seal) may run from `end_slot + 1`.
+/// The `start_slot` lower bound is checked separately by the voting paths.
+pub fn is_voting_open(proposal: &Proposal, slot: u64) -> bool {
+    slot <= proposal.end_slot
+}
+
+/// Derive a proposal's phase at `slot`, with the slots remaining until the next
+/// phase when that transition is purely time-driven.
+pub fn derive_phase(
+    proposal: &Proposal,
+    governance: &Governance,
+    slot: u64,
+) -> (ProposalPhase, Option<u64>) {
+    match proposal.status {
+        ProposalStatus::PendingEpiOverride => (ProposalPhase::PendingApproval, None),
+        ProposalStatus::Active => {
+            if slot < proposal.start_slot {
+                (ProposalPhase::Draft, Some(proposal.start_slot - slot))
+            } else if is_voting_open(proposal, slot) {
+                (ProposalPhase::Voting, Some(proposal.end_slot - slot + 1))
+            } else {
+                (ProposalPhase::VotingEnded, None)
+            }
+        }
+        ProposalStatus::Succeeded if proposal.is_ratification => (ProposalPhase::Succeeded, None),
+        ProposalStatus::Succeeded => {
+            let executable_at = proposal
+                .queued_at_slot
+                .saturating_add(governance.execution_delay);
+            if governance.veto_window > 0 && governance.veto_window_open(proposal, slot) {
+                let closes_at = proposal.end_slot.saturating_add(governance.veto_window);
+                (ProposalPhase::VetoWindow, Some(closes_at - slot + 1))
+            } else if slot < executable_at {
+                (ProposalPhase::InTimelock, Some(executable_at - slot))
+            } else {
+                (ProposalPhase::Executable, None)
+            }
+        }
+        ProposalStatus::Executed => (ProposalPhase::Executed, None),
+        ProposalStatus::Defeated => (ProposalPhase::Defeated, None),
+        ProposalStatus::Vetoed => (ProposalPhase::Vetoed, None),
+        ProposalStatus::Cancelled => (ProposalPhase::Cancelled, None),
+        ProposalStatus::ExecutionFailed => (ProposalPhase::Expired, None),
+    }
+}
+
+/// Accept the guardian itself or its deputy while the delegation is unexpired.
+fn authorize_veto_signer(guardian: &GuardianAccount, signer: &Pubkey, slot: u64) -> Result<()> {
+    if *signer == guardian.guardian {
+        return Ok(());
+    }
+    match guardian.veto_delegate {
+        Some(delegation) if delegation.deputy == *signer => {
+            require!(
+                slot <= delegation.expires_at_slot,
+                GovernanceError::VetoDelegationExpired
+            );
+            Ok(())
+        }
+        _ => Err(GovernanceError::Unauthorized.into()),
+    }
+}
+
+fn voting_end_slot(start_slot: u64, voting_period: u64) -> Result<u64> {
+    start_slot
+        .checked_add(voting_period)
+        .ok_or_else(|| GovernanceError::SlotOverflow.into())
+}
+
+/// Estimated unix time of `slot`, extrapolated from the current clock at
+/// `MS_PER_SLOT` per slot. Only an estimate: real slot times drift.
+pub fn slot_timestamp(clock: &Clock, slot: u64) -> i64 {
+    let delta_ms = (slot as i128 - clock.slot as i128) * MS_PER_SLOT as i128;
+    let timestamp = clock.unix_timestamp as i128 + delta_ms / 1000;
+    timestamp.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+}
+
+/// Deserialize a proposal passed through remaining accounts, checking it is
+/// owned by this program and sits at its canonical PDA.
+fn load_proposal(info: &AccountInfo) -> Result<Proposal> {
+    require_keys_eq!(*info.owner, crate::ID, GovernanceError::InvalidProposal);
+    let data = info.try_borrow_data()?;
+    let proposal = Proposal::try_deserialize(&mut &data[..])?;
+    require_keys_eq!(
+        info.key(),
+        Proposal::address(proposal.id),
+        GovernanceError::InvalidProposal
+    );
+    Ok(proposal)
+}
+
+fn tally_bucket(proposal: &mut Proposal, support: u8) -> Result<&mut u64> {
+    if support == proposal.abstain_option() {
+        return Ok(&mut proposal.votes_abstain);
+    }
+    if !proposal.is_binary() {
+        return proposal
+            .option_votes
+            .get_mut(support as usize)
+            .ok_or_else(|| GovernanceError::InvalidVoteType.into());
+    }
+    match support {
+        0 => Ok(&mut proposal.votes_against),
+        1 => Ok(&mut proposal.votes_for),
+        _ => Err(GovernanceError::InvalidVoteType.into()),
+    }
+}
+
+fn add_to_tally(proposal: &mut Proposal, support: u8, weight: u64) -> Result<()> {
+    let bucket = tally_bucket(proposal, support)?;
+    *bucket = bucket
+        .checked_add(weight)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+fn remove_from_tally(proposal: &mut Proposal, support: u8, weight: u64) -> Result<()> {
+    let bucket = tally_bucket(proposal, support)?;
+    *bucket = bucket
+        .checked_sub(weight)
+        .ok_or(GovernanceError::ArithmeticOverflow)?;
+    Ok(())
+}
+
+/// Run instant-runoff rounds on a ranked proposal as far as `ballots` allow
+///
+/// A round eliminates one option and moves each ballot counted for it to the
+/// ballot's next continuing choice, or to `exhausted_votes` when none is
+/// left. The round ends once the eliminated option's tally reaches zero;
+/// until then it stays in `eliminating_option` for a later call with the
+/// remaining ballots. Ballots already moved are skipped, so one passed again
+/// in a later call is harmless.
+fn advance_instant_runoff<B: std::ops::DerefMut<Target = VoteRecord>>(
+    proposal: &mut Proposal,
+    ballots: &mut [B],
+    timestamp: i64,
+) -> Result<()> {
+    loop {
+        let eliminated = match proposal.eliminating_option {
+            Some(option) => option,
+            None => match proposal.next_elimination() {
+                Some(option) => {
+                    proposal.current_round = proposal
+                        .current_round
+                        .checked_add(1)
+                        .ok_or(GovernanceError::ArithmeticOverflow)?;
+                    proposal.eliminated_options |= 1 << option;
+                    proposal.eliminating_option = Some(option);
+                    emit!(RankedRoundStarted {
+                        proposal_id: proposal.id,
+                        round: proposal.current_round,
+                        eliminated_option: option,
+                        timestamp,
+                    });
+                    option
+                }
+                None => {
+                    proposal.ranked_tally_complete = true;
+                    emit!(RankedTallyCompleted {
+                        proposal_id: proposal.id,
+                        rounds: proposal.current_round,
+                        exhausted_votes: proposal.exhausted_votes,
+                        timestamp,
+                    });
+                    return Ok(());
+                }
+            },
+        };
+
+        for ballot in ballots.iter_mut() {
+            transfer_ballot(proposal, ballot, eliminated)?;
+        }
+        if proposal.option_tally(eliminated) > 0 {
+            return Ok(());
+        }
+        proposal.eliminating_option = None;
+    }
+}
+
+/// Move `ballot` off the `eliminated` option if it is counted there
+fn transfer_ballot(proposal: &mut Proposal, ballot: &mut VoteRecord, eliminated: u8) -> Result<()> {
+    require!(
+        ballot.proposal_id == proposal.id,
+        GovernanceError::InvalidVoteRecord
+    );
+    if ballot.counted_option != eliminated {
+        return Ok(());
+    }
+    remove_from_tally(proposal, eliminated, ballot.voting_power)?;
+    let next_choice = ballot
+        .rankings
+        .iter()
+        .copied()
+        .find(|&option| !proposal.is_eliminated(option));
+    match next_choice {
+        Some(option) => {
+            add_to_tally(proposal, option, ballot.voting_power)?;
+            ballot.counted_option = option;
+        }
+        None => {
+            proposal.exhausted_votes = proposal
+                .exhausted_votes
+                .checked_add(ballot.voting_power)
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
+            ballot.counted_option = EXHAUSTED_BALLOT;
+        }
+    }
+    Ok(())
+}
+
+fn set_submission_paused(governance: &mut Governance, paused: bool) -> Result<()> {
+    governance.submission_paused = paused;
+
+    emit!(SubmissionPauseToggled {
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Proposal submissions paused: {}", paused);
+    Ok(())
+}
+
+fn set_governance_paused(governance: &mut Governance, paused: bool) -> Result<()> {
+    governance.is_paused = paused;
+
+    emit!(GovernancePauseToggled {
+        paused,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Governance paused: {}", paused);
+    Ok(())
+}
+
+/// Outcome of running a proposal's scores through the EPI gates
+pub struct EpiEvaluation {
+    /// Weighted profit/ethics score computed on-chain
+    pub epi_score: u64,
+    pub sub_scores: Vec<SubScore>,
+    pub composite: Option<u64>,
+    pub meets_threshold: bool,
+}
+
+/// Validate score ranges and dimension floors, derive the EPI score from its
+/// components, then check it against `epi_threshold`.
+/// Failing the threshold is reported rather than rejected, so callers decide.
+pub fn evaluate_epi(
+    governance: &Governance,
+    profit_score: u64,
+    ethics_score: u64,
+    extra_sub_scores: &[SubScore],
+    epi_threshold: u64,
+) -> Result<EpiEvaluation> {
+    require!(profit_score <= MAX_EPI_SCORE, GovernanceError::InvalidScore);
+    require!(ethics_score <= MAX_EPI_SCORE, GovernanceError::InvalidScore);
+
+    let epi_score = compute_epi(governance, profit_score, ethics_score);
+    let sub_scores = build_sub_scores(profit_score, ethics_score, extra_sub_scores)?;
+    let composite = check_epi_dimensions(&sub_scores, governance)?;
+    let meets_threshold = meets_epi_threshold(epi_score, composite, epi_threshold);
+
+    Ok(EpiEvaluation {
+        epi_score,
+        sub_scores,
+        composite,
+        meets_threshold,
+    })
+}
+
+/// `(profit * profit_weight + ethics * ethics_weight) / 10000`; weights sum to
+/// 10000, so the result stays within the score range
+pub fn compute_epi(governance: &Governance, profit_score: u64, ethics_score: u64) -> u64 {
+    let weighted = profit_score as u128 * governance.profit_weight as u128
+        + ethics_score as u128 * governance.ethics_weight as u128;
+    (weighted / 10000) as u64
+}
+
+/// Merge the legacy profit/ethics scores (dimensions 0 and 1) with any
+/// additional dimensions, rejecting out-of-range or repeated dimensions.
+pub fn build_sub_scores(
+    profit_score: u64,
+    ethics_score: u64,
+    extra: &[SubScore],
+) -> Result<Vec<SubScore>> {
+    require!(
+        extra.len() <= MAX_EPI_DIMENSIONS - 2,
+        GovernanceError::InvalidDimension
+    );
+
+    let mut sub_scores = vec![
+        SubScore {
+            dimension: DIMENSION_PROFIT,
+            value: profit_score,
+        },
+        SubScore {
+            dimension: DIMENSION_ETHICS,
+            value: ethics_score,
+        },
+    ];
+    for score in extra {
+        require!(
+            (score.dimension as usize) < MAX_EPI_DIMENSIONS,
+            GovernanceError::InvalidDimension
+        );
+        require!(score.value <= MAX_EPI_SCORE, GovernanceError::InvalidScore);
+        require!(
+            sub_scores.iter().all(|s| s.dimension != score.dimension),
+            GovernanceError::DuplicateDimension
+        );
+        sub_scores.push(*score);
+    }
+    Ok(sub_scores)
+}
+
+/// Weighted composite over the provided dimensions, or `None` if none of them
+/// carries a weight.
+pub fn composite_epi(sub_scores: &[SubScore], weights: &[u64; MAX_EPI_DIMENSIONS]) -> Option<u64> {
+    let mut weighted: u128 = 0;
+    let mut total_weight: u128 = 0;
+    for score in sub_scores {
+        let weight = weights[score.dimension as usize] as u128;
+        weighted += weight * score.value as u128;
+        total_weight += weight;
+    }
+    if total_weight == 0 {
+        return None;
+    }
+    Some((weighted / total_weight) as u64)
+}
+
+/// Enforce per-dimension floors and return the weighted composite, if any.
+pub fn check_epi_dimensions(
+    sub_scores: &[SubScore],
+    governance: &Governance,
+) -> Result<Option<u64>> {
+    for score in sub_scores {
+        require!(
+            score.value >= governance.dimension_floors[score.dimension as usize],
+            GovernanceError::DimensionBelowFloor
+        );
+    }
+    Ok(composite_epi(sub_scores, &governance.dimension_weights))
+}
+
+/// Whether the EPI score and, when weights are configured, the composite
+/// both clear `epi_threshold`.
+pub fn meets_epi_threshold(epi_score: u64, composite: Option<u64>, epi_threshold: u64) -> bool {
+    let composite_ok = match composite {
+        Some(composite) => composite >= epi_threshold,
+        None => true,
+    };
+    epi_score >= epi_threshold && composite_ok
+}
+
+/// Merkle root over `leaves` using sorted-pair SHA-256 hashing.
+///
+/// An odd node at the end of a level is promoted unchanged. Sorting each pair
+/// means inclusion proofs need no left/right flags. An empty set hashes to zero.
+pub fn compute_merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => {
+                    let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+                    hashv(&[lo, hi]).to_bytes()
+                }
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+// ============ Account Contexts ============
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Governance::INIT_SPACE,
+        seeds = [b"governance"],
+        bump
+    )]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DepositVault::INIT_SPACE,
+        seeds = [b"deposit_vault"],
+        bump
+    )]
+    pub deposit_vault: Account<'info, DepositVault>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ProposalParams)]
+pub struct SubmitProposal<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"deposit_vault"], bump = deposit_vault.bump)]
+    pub deposit_vault: Account<'info, DepositVault>,
+    /// CHECK: `ThoughtHashRegistry` PDA for `thought_hash`, created in the handler
+    #[account(mut, seeds = [b"thought_hash", params.thought_hash.as_ref()], bump)]
+    pub thought_hash_registry: UncheckedAccount<'info>,
+    /// Required when `governance.min_proposal_power` or `proposal_cooldown`
+    /// is non-zero
+    #[account(mut, seeds = [b"voter", proposer.key().as_ref()], bump = proposer_account.bump)]
+    pub proposer_account: Option<Account<'info, VoterAccount>>,
+    /// Proposer's token account for the gate mint; required only when
+    /// `governance.proposal_gate_mint` is set
+    pub proposer_token_account: Option<Account<'info, TokenAccount>>,
+    /// Proposal named by `supersedes`; required only when it is set
+    #[account(mut)]
+    pub superseded_proposal: Option<Account<'info, Proposal>>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitEmergencyProposal<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(seeds = [b"guardian", proposer.key().as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SubmitRatification<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    #[account(seeds = [b"guardian", proposer.key().as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RecordEpiRejection<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + RejectedProposalLog::INIT_SPACE,
+        seeds = [b"epi_rejection", governance.epi_rejection_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub rejection_log: Account<'info, RejectedProposalLog>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct AppealEpi<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + EpiAppeal::INIT_SPACE,
+        seeds = [b"epi_appeal", governance.appeal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub appeal: Account<'info, EpiAppeal>,
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(appeal_id: u64)]
+pub struct ResolveAppeal<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"epi_appeal", appeal_id.to_le_bytes().as_ref()], bump = appeal.bump)]
+    pub appeal: Account<'info, EpiAppeal>,
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: `ThoughtHashRegistry` PDA for the appeal's thought hash, created in the handler
+    #[account(mut, seeds = [b"thought_hash", appeal.thought_hash.as_ref()], bump)]
+    pub thought_hash_registry: UncheckedAccount<'info>,
+    #[account(seeds = [b"guardian", guardian.key().as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct FinalizeRatification<'info> {
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ApproveEpiOverride<'info> {
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(seeds = [b"guardian", guardian.key().as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + EpiOverrideApproval::INIT_SPACE,
+        seeds = [b"epi_override", proposal_id.to_le_bytes().as_ref(), guardian.key().as_ref()],
+        bump
+    )]
+    pub override_approval: Account<'info, EpiOverrideApproval>,
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct Vote<'info> {
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ChangeVote<'info> {
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(
+        mut,
+        seeds = [b"vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct WithdrawVote<'info> {
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(
+        mut,
+        close = voter,
+        seeds = [b"vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AbstainAll<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct VoteWithCredits<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(mut, seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [b"vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
+        bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    #[account(mut)]
+    pub voter: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ExecuteProposal<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Oracle named by the proposal's execution condition; key checked in handler
+    pub oracle: Option<UncheckedAccount<'info>>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    /// CHECK: Disbursement destination; required when the proposal names a
+    /// recipient, key checked in handler
+    #[account(mut)]
+    pub recipient: Option<UncheckedAccount<'info>>,
+    pub executor: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DepositTreasury<'info> {
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct FinalizeProposal<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct FinalizeRound<'info> {
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ClaimDeposit<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"deposit_vault"], bump = deposit_vault.bump)]
+    pub deposit_vault: Account<'info, DepositVault>,
+    #[account(mut, seeds = [b"treasury"], bump = treasury.bump)]
+    pub treasury: Account<'info, Treasury>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Refund destination, constrained to the proposal's proposer
+    #[account(mut, address = proposal.proposer @ GovernanceError::Unauthorized)]
+    pub proposer: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CloseProposal<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump
+    )]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: Rent destination, constrained to the proposal's proposer
+    #[account(mut, address = proposal.proposer @ GovernanceError::Unauthorized)]
+    pub proposer: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64, voter: Pubkey)]
+pub struct CloseVoteRecord<'info> {
+    /// CHECK: Only checked to have been closed, at the proposal PDA
+    #[account(seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump)]
+    pub proposal: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = voter_wallet,
+        seeds = [b"vote", proposal_id.to_le_bytes().as_ref(), voter.as_ref()],
+        bump = vote_record.bump
+    )]
+    pub vote_record: Account<'info, VoteRecord>,
+    /// CHECK: Rent destination, constrained to the voter who paid for the record
+    #[account(mut, address = voter @ GovernanceError::Unauthorized)]
+    pub voter_wallet: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct AbandonHopelessProposal<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ReadProposal<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ResolveTie<'info> {
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    /// CHECK: SlotHashes sysvar, read as raw bytes
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct ReportExecutionFailure<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct AdvanceExecutionQueue<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct FreezeProposal<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(seeds = [b"guardian", guardian.key().as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct VetoProposal<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(
+        mut,
+        seeds = [b"guardian", guardian_account.guardian.as_ref()],
+        bump = guardian_account.bump
+    )]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    #[account(
+        init,
+        payer = guardian,
+        space = 8 + GuardianVetoRecord::INIT_SPACE,
+        seeds = [b"guardian_veto", proposal_id.to_le_bytes().as_ref(), guardian_account.guardian.as_ref()],
+        bump
+    )]
+    pub veto_record: Account<'info, GuardianVetoRecord>,
+    /// The guardian or their veto deputy
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ManageVetoDelegation<'info> {
+    #[account(mut, seeds = [b"guardian", guardian.key().as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    pub guardian: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct OverrideVeto<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CorrectProposalScores<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        mut,
+        seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+        constraint = proposal.proposer == proposer.key() @ GovernanceError::Unauthorized
+    )]
+    pub proposal: Account<'info, Proposal>,
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct AttachDeliberation<'info> {
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    /// The proposer or the governance authority
+    pub signer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct InvalidateProposal<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    #[account(seeds = [b"voter", proposal.proposer.as_ref()], bump = proposer_account.bump)]
+    pub proposer_account: Account<'info, VoterAccount>,
+    pub caller: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct CancelProposal<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct AmendProposal<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub proposer: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct SealProposal<'info> {
+    #[account(constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Account<'info, Proposal>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(proposal_id: u64)]
+pub struct LogThought<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = logger,
+        space = 8 + ThoughtLog::INIT_SPACE,
+        seeds = [b"thought", logger.key().as_ref(), governance.thought_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub thought_log: Account<'info, ThoughtLog>,
+    #[account(seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
+    pub proposal: Option<Account<'info, Proposal>>,
+    #[account(mut)]
+    pub logger: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterVoter<'info> {
+    #[account(mut)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + VoterAccount::INIT_SPACE,
+        seeds = [b"voter", voter.key().as_ref()],
+        bump
+    )]
+    pub voter_account: Account<'info, VoterAccount>,
+    /// CHECK: Voter being registered
+    pub voter: UncheckedAccount<'info>,
+    #[account(mut, constraint = authority.key() == governance.authority @ GovernanceError::Unauthorized)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(guardian: Pubkey)]
+pub struct RemoveGuardian<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"guardian", guardian.as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(guardian: Pubkey)]
+pub struct RenewGuardian<'info> {
+    #[account(constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"guardian", guardian.as_ref()], bump = guardian_account.bump)]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AddGuardian<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GuardianAccount::INIT_SPACE,
+        seeds = [b"guardian", new_guardian.key().as_ref()],
+        bump
+    )]
+    pub guardian_account: Account<'info, GuardianAccount>,
+    /// CHECK: New guardian
+    pub new_guardian: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetCreditBudget<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"voter", voter_account.voter.as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SetNotificationPrefs<'info> {
+    #[account(mut, seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterVotersBatch<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(voter: Pubkey)]
+pub struct DeregisterVoter<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"voter", voter.as_ref()],
+        bump = voter_account.bump
+    )]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(mut, seeds = [b"voter", delegate_account.voter.as_ref()], bump = delegate_account.bump)]
+    pub delegate_account: Option<Account<'info, VoterAccount>>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(voter: Pubkey)]
+pub struct UpdateVotingPower<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    #[account(mut, seeds = [b"voter", voter.as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(mut, seeds = [b"voter", delegate_account.voter.as_ref()], bump = delegate_account.bump)]
+    pub delegate_account: Option<Account<'info, VoterAccount>>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct Delegate<'info> {
+    #[account(mut, seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(mut, seeds = [b"voter", delegate.as_ref()], bump = delegate_account.bump)]
+    pub delegate_account: Account<'info, VoterAccount>,
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Undelegate<'info> {
+    #[account(
+        mut,
+        seeds = [b"voter", voter.key().as_ref()],
+        bump = voter_account.bump,
+        constraint = voter_account.delegate == Some(delegate_account.voter) @ GovernanceError::NotDelegated
+    )]
+    pub voter_account: Account<'info, VoterAccount>,
+    #[account(mut, seeds = [b"voter", delegate_account.voter.as_ref()], bump = delegate_account.bump)]
+    pub delegate_account: Account<'info, VoterAccount>,
+    pub voter: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReadGovernance<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+}
+
+#[derive(Accounts)]
+pub struct ReadVoter<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(seeds = [b"voter", voter_account.voter.as_ref()], bump = voter_account.bump)]
+    pub voter_account: Account<'info, VoterAccount>,
+}
+
+#[derive(Accounts)]
+pub struct ReadGuardian<'info> {
+    #[account(seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    #[account(
+        seeds = [b"guardian", guardian_account.guardian.as_ref()],
+        bump = guardian_account.bump
+    )]
+    pub guardian_account: Account<'info, GuardianAccount>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGovernance<'info> {
+    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
+    pub governance: Account<'info, Governance>,
+    pub authority: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthorityTransfer<'info> {
+    #[account(mut, seeds = [b"governance"], bump = governance.bump)]
+    pub governance: Account<'info, Governance>,
+    pub new_authority: Signer<'info>,
+}
+
+// ============ State Accounts ============
+
+/// Upper bound of every EPI score, sub-score and threshold (fixed-point 0-1.0)
+pub const MAX_EPI_SCORE: u64 = 1_000_000;
+
+/// Number of EPI dimensions a proposal can be scored on
+pub const MAX_EPI_DIMENSIONS: usize = 4;
+pub const DIMENSION_PROFIT: u8 = 0;
+pub const DIMENSION_ETHICS: u8 = 1;
+pub const DIMENSION_SUSTAINABILITY: u8 = 2;
+pub const DIMENSION_COMPLIANCE: u8 = 3;
+
+pub const DEFAULT_MAX_EXECUTION_ATTEMPTS: u8 = 3;
+
+/// Guardian cap a new governance starts with
+pub const DEFAULT_MAX_GUARDIANS: u64 = 16;
+
+/// Most deliberation references a proposal can carry
+pub const MAX_DELIBERATIONS: usize = 4;
+
+/// Largest instruction payload a proposal can carry
+pub const MAX_INSTRUCTION_DATA_LEN: usize = 512;
+
+/// Longest justification stored on a vote record
+pub const MAX_VOTE_REASON_LEN: usize = 256;
+
+/// Submitted proposals remembered in `Governance::recent_epi_scores`
+pub const EPI_HISTORY_LEN: usize = 16;
+
+/// Longest justification stored on a guardian veto record
+pub const MAX_VETO_REASON_LEN: usize = 256;
+
+/// Most proposals `abstain_all` handles in one transaction
+pub const MAX_ABSTAIN_BATCH: usize = 5;
+
+/// Most voters `register_voters_batch` creates in one transaction
+pub const MAX_REGISTRATION_BATCH: usize = 10;
+
+/// Seats on the authority council
+pub const MAX_AUTHORITY_SIGNERS: usize = 5;
+
+/// Number of proposal categories
+pub const MAX_CATEGORIES: usize = 8;
+
+/// Capacity of `Governance::execution_target_allowlist`
+pub const MAX_EXECUTION_TARGETS: usize = 16;
+
+/// Defeated proposals with less support than this (basis points of all votes)
+/// forfeit their deposit
+pub const DEPOSIT_FORFEIT_SUPPORT_BPS: u64 = 1000;
+
+/// Longest allowed voting period: roughly one year of 400ms slots
+pub const MAX_VOTING_PERIOD: u64 = 78_840_000;
+
+/// Target slot duration, used to estimate wall-clock times from slots
+pub const MS_PER_SLOT: u64 = 400;
+
+/// Votes count with their full voting power
+pub const VOTING_MODE_LINEAR: u8 = 0;
+/// Votes count with the integer square root of their voting power
+pub const VOTING_MODE_QUADRATIC: u8 = 1;
+
+/// `ExecutionCondition::operator` values: oracle value <op> threshold
+pub const CONDITION_GT: u8 = 0;
+pub const CONDITION_GTE: u8 = 1;
+pub const CONDITION_LT: u8 = 2;
+pub const CONDITION_LTE: u8 = 3;
+pub const CONDITION_EQ: u8 = 4;
+
+/// `VoterAccount::notification_prefs` bits
+pub const NOTIFY_NEW_PROPOSAL: u8 = 1 << 0;
+pub const NOTIFY_VOTE_REMINDER: u8 = 1 << 1;
+pub const NOTIFY_EXECUTION: u8 = 1 << 2;
+pub const NOTIFY_ALL: u8 = NOTIFY_NEW_PROPOSAL | NOTIFY_VOTE_REMINDER | NOTIFY_EXECUTION;
+
+/// Hard cap on proposal options, bounding per-option tally allocation and
+/// finalization compute
+pub const MAX_PROPOSAL_OPTIONS: u8 = 8;
+
+/// `VoteRecord::counted_option` of a ranked ballot whose choices have all
+/// been eliminated
+pub const EXHAUSTED_BALLOT: u8 = u8::MAX;
+
+/// Space reserved for a proposal's title and description; the configured
+/// `max_title_len` / `max_description_len` may only tighten these
+pub const MAX_TITLE_LEN: u16 = 64;
+pub const MAX_DESCRIPTION_LEN: u16 = 256;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Governance {
+    pub authority: Pubkey,
+    /// Nominee that must accept before becoming `authority`
+    pub pending_authority: Option<Pubkey>,
+    /// Authority council; `Pubkey::default()` marks an empty seat
+    pub authority_signers: [Pubkey; MAX_AUTHORITY_SIGNERS],
+    /// Council members that must approve privileged instructions; 0 disables
+    pub authority_threshold: u8,
+    pub epi_threshold: u64,
+    pub voting_period: u64,
+    /// Share of the power snapshot that must turn out, in basis points
+    /// (5100 = 51%)
+    pub quorum_bps: u64,
+    /// Slots a succeeded proposal must wait before it can be executed
+    pub execution_delay: u64,
+    pub proposal_count: u64,
+    /// Number of EPI appeals filed; the next appeal's id
+    pub appeal_count: u64,
+    /// Number of recorded EPI gate rejections; the next log's id
+    pub epi_rejection_count: u64,
+    /// Next execution-queue position handed to a succeeded proposal
+    pub finalize_sequence: u64,
+    /// Queue position of the next proposal allowed to execute
+    pub execution_sequence: u64,
+    /// Thought logs recorded so far; seeds the next `ThoughtLog` PDA
+    pub thought_count: u64,
+    pub total_voting_power: u64,
+    /// Sum of `isqrt(voting_power)` over registered voters
+    pub total_quadratic_power: u64,
+    /// `VOTING_MODE_LINEAR` or `VOTING_MODE_QUADRATIC`, fixed at initialization
+    pub voting_mode: u8,
+    /// Whether abstain votes count toward quorum turnout, fixed at initialization
+    pub abstain_counts_for_quorum: bool,
+    /// Lamports a proposer locks in the deposit vault per proposal
+    pub proposal_deposit: u64,
+    /// Basis-point weights of profit and ethics in the computed EPI score
+    pub profit_weight: u64,
+    pub ethics_weight: u64,
+    pub is_paused: bool,
+    pub submission_paused: bool,
+    pub max_execution_attempts: u8,
+    pub max_options: u8,
+    /// Longest accepted proposal title, at most `MAX_TITLE_LEN`
+    pub max_title_len: u16,
+    /// Longest accepted proposal description, at most `MAX_DESCRIPTION_LEN`
+    pub max_description_len: u16,
+    pub epi_override_threshold: u64,
+    pub veto_decay_window: u64,
+    pub veto_decay_per_window: u64,
+    pub sortition_tiebreak: bool,
+    /// Whether an exact for/against tie passes; takes precedence over sortition
+    pub tie_breaks_for: bool,
+    /// Whether a proposal whose outcome is already decided may be finalized
+    /// and executed before `end_slot`
+    pub allow_early_execution: bool,
+    /// Credit budget not yet spent across all voters, bounding the votes
+    /// credits can still buy
+    pub total_credit_budget: u64,
+    /// Voting period of guardian emergency proposals, in slots; 0 disables them
+    pub emergency_voting_period: u64,
+    /// Quorum of emergency proposals in basis points
+    pub emergency_quorum: u64,
+    /// Programs proposals may execute against; empty allows any
+    #[max_len(MAX_EXECUTION_TARGETS)]
+    pub execution_target_allowlist: Vec<Pubkey>,
+    pub min_margin: u64,
+    /// Distinct voters a proposal needs on top of the power-based quorum
+    pub min_voters: u64,
+    pub min_document_schema_version: u16,
+    /// Proposals per category currently in flight (active or awaiting override)
+    pub category_active_counts: [u64; MAX_CATEGORIES],
+    pub max_active_per_category: u64,
+    pub snapshot_staleness_slots: u64,
+    /// Global executor, used when a category has no executor of its own
+    pub execution_authority: Option<Pubkey>,
+    /// Guardian veto weight needed against a proposal with unanimous support
+    pub base_veto_threshold: u64,
+    /// Active guardians
+    pub guardian_count: u64,
+    /// Most guardians that may be active at once
+    pub max_guardians: u64,
+    /// Sum of `veto_weight` over active guardians
+    pub total_guardian_weight: u64,
+    /// Seconds a guardian term lasts from appointment or renewal; 0 never expires
+    pub guardian_term_length: u64,
+    /// Minimum accumulated guardian veto weight needed to veto a proposal
+    pub veto_threshold: u64,
+    /// Basis points of the power snapshot `votes_for` must exceed to override a veto
+    pub override_threshold: u64,
+    pub category_execution_authorities: [Option<Pubkey>; MAX_CATEGORIES],
+    /// Per-category quorum in basis points; 0 falls back to `quorum_bps`
+    pub category_quorums: [u64; MAX_CATEGORIES],
+    pub min_proposal_power: u64,
+    /// Registered power (own plus delegated) a voter needs to cast a vote
+    pub min_vote_power: u64,
+    pub dimension_weights: [u64; MAX_EPI_DIMENSIONS],
+    pub dimension_floors: [u64; MAX_EPI_DIMENSIONS],
+    /// Slots after `end_slot` during which guardians may veto; 0 means no limit
+    pub veto_window: u64,
+    /// Slots a guardian must wait between vetoes; 0 disables the limit
+    pub veto_cooldown: u64,
+    /// Ethics justification a guardian veto must cite; 0 uses `epi_threshold`
+    pub veto_ethics_threshold: u64,
+    /// Ring buffer of the EPI scores of the latest submitted proposals
+    pub recent_epi_scores: [u64; EPI_HISTORY_LEN],
+    /// Slot in `recent_epi_scores` the next score is written to
+    pub recent_epi_index: u8,
+    /// Mint a proposer must hold a positive balance of to submit, if any
+    pub proposal_gate_mint: Option<Pubkey>,
+    /// Slots a proposer must wait between submissions; 0 disables the limit
+    pub proposal_cooldown: u64,
+    /// Longest a proposer may postpone the start of voting, in slots
+    pub max_start_delay: u64,
+    /// Slots after `end_slot` before a terminal proposal may be closed
+    pub cleanup_delay: u64,
+    /// Slots before `end_slot` in which a lead-changing vote extends voting; 0 disables
+    pub extension_window: u64,
+    /// Slots added to `end_slot` per extension
+    pub extension_period: u64,
+    /// Most extensions a single proposal can receive
+    pub max_extensions: u8,
+    pub bump: u8,
+}
+
+impl Governance {
+    /// Whether `key` holds a seat on the authority council
+    pub fn is_authority_signer(&self, key: &Pubkey) -> bool {
+        *key != Pubkey::default() && self.authority_signers.contains(key)
+    }
+
+    /// Occupied seats on the authority council
+    pub fn authority_signer_count(&self) -> usize {
+        self.authority_signers
+            .iter()
+            .filter(|member| **member != Pubkey::default())
+            .count()
+    }
+
+    /// Add a newly registered voter's power to the electorate totals
+    pub fn add_voting_power(&mut self, voting_power: u64) -> Result<()> {
+        self.total_voting_power = self
+            .total_voting_power
+            .checked_add(voting_power)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.total_quadratic_power = self
+            .total_quadratic_power
+            .checked_add(isqrt(voting_power))
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Remove a departing voter's power from the electorate totals
+    pub fn remove_voting_power(&mut self, voting_power: u64) -> Result<()> {
+        self.total_voting_power = self
+            .total_voting_power
+            .checked_sub(voting_power)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.total_quadratic_power = self
+            .total_quadratic_power
+            .checked_sub(isqrt(voting_power))
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Tally weight of `voting_power` under the configured voting mode
+    pub fn vote_weight(&self, voting_power: u64) -> u64 {
+        if self.voting_mode == VOTING_MODE_QUADRATIC {
+            isqrt(voting_power)
+        } else {
+            voting_power
+        }
+    }
+
+    /// Electorate size in the same units as `vote_weight`
+    pub fn effective_total_power(&self) -> u64 {
+        if self.voting_mode == VOTING_MODE_QUADRATIC {
+            self.total_quadratic_power
+        } else {
+            self.total_voting_power
+        }
+    }
+
+    /// Required executor for a category: its own, else the global one, else
+    /// `None` for permissionless execution
+    pub fn execution_authority_for(&self, category: u8) -> Option<Pubkey> {
+        self.category_execution_authorities[category as usize].or(self.execution_authority)
+    }
+
+    /// Push a submitted proposal's EPI score into the history ring buffer
+    pub fn record_epi_score(&mut self, epi_score: u64) {
+        let index = self.recent_epi_index as usize % EPI_HISTORY_LEN;
+        self.recent_epi_scores[index] = epi_score;
+        self.recent_epi_index = ((index + 1) % EPI_HISTORY_LEN) as u8;
+    }
+
+    /// Mean of the non-zero entries in the EPI history, 0 when empty
+    pub fn average_epi(&self) -> u64 {
+        let (sum, count) = self
+            .recent_epi_scores
+            .iter()
+            .filter(|score| **score > 0)
+            .fold((0u128, 0u128), |(sum, count), score| {
+                (sum + *score as u128, count + 1)
+            });
+        sum.checked_div(count).unwrap_or(0) as u64
+    }
+
+    /// Whether guardians may still veto `proposal`: until `veto_window` slots
+    /// after voting ends, or indefinitely when `veto_window` is 0
+    pub fn veto_window_open(&self, proposal: &Proposal, slot: u64) -> bool {
+        self.veto_window == 0 || slot <= proposal.end_slot.saturating_add(self.veto_window)
+    }
+
+    /// Minimum ethics justification score for a guardian veto
+    pub fn veto_ethics_bar(&self) -> u64 {
+        match self.veto_ethics_threshold {
+            0 => self.epi_threshold,
+            threshold => threshold,
+        }
+    }
+
+    /// End of a guardian term starting at `now`
+    pub fn guardian_term_end(&self, now: i64) -> Result<i64> {
+        if self.guardian_term_length == 0 {
+            return Ok(i64::MAX);
+        }
+        i64::try_from(self.guardian_term_length)
+            .ok()
+            .and_then(|length| now.checked_add(length))
+            .ok_or_else(|| GovernanceError::ArithmeticOverflow.into())
+    }
+
+    /// Quorum (basis points) for a category: its own override if set, else the
+    /// global `quorum_bps`
+    pub fn quorum_bps_for(&self, category: u8) -> u64 {
+        match self.category_quorums[category as usize] {
+            0 => self.quorum_bps,
+            quorum => quorum,
+        }
+    }
+
+    /// Give a newly succeeded proposal the next execution-queue position;
+    /// a proposal keeps the position it was first given
+    pub fn enqueue_for_execution(&mut self, proposal: &mut Proposal) -> Result<()> {
+        if proposal.finalize_order.is_none() {
+            proposal.finalize_order = Some(self.finalize_sequence);
+            self.finalize_sequence = self
+                .finalize_sequence
+                .checked_add(1)
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Move the execution queue past `proposal` if it is at the head
+    pub fn advance_execution_sequence(&mut self, proposal: &Proposal) -> Result<()> {
+        if proposal.finalize_order == Some(self.execution_sequence) {
+            self.execution_sequence = self
+                .execution_sequence
+                .checked_add(1)
+                .ok_or(GovernanceError::ArithmeticOverflow)?;
+        }
+        Ok(())
+    }
+
+    /// Free the category slot a proposal held while in flight. Ratifications
+    /// never take a slot.
+    pub fn release_active_slot(&mut self, proposal: &Proposal) {
+        if proposal.is_ratification {
+            return;
+        }
+        let count = &mut self.category_active_counts[proposal.category as usize];
+        *count = count.saturating_sub(1);
+    }
+
+    /// An empty allowlist permits any target
+    pub fn is_execution_target_allowed(&self, target: &Pubkey) -> bool {
+        self.execution_target_allowlist.is_empty()
+            || self.execution_target_allowlist.contains(target)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub id: u64,
+    pub proposer: Pubkey,
+    /// Lamports held in the deposit vault for this proposal
+    pub deposit: u64,
+    pub deposit_settled: bool,
+    #[max_len(MAX_TITLE_LEN as usize)]
+    pub title: String,
+    #[max_len(MAX_DESCRIPTION_LEN as usize)]
+    pub description: String,
+    pub epi_score: u64,
+    pub profit_score: u64,
+    pub ethics_score: u64,
+    #[max_len(MAX_EPI_DIMENSIONS)]
+    pub sub_scores: Vec<SubScore>,
+    pub composite_epi: u64,
+    /// EPI bar this proposal was held to: the global threshold at submission,
+    /// or the proposer's stricter override
+    pub epi_threshold: u64,
+    pub ipfs_hash: [u8; 32],
+    /// Schema version of the off-chain document at `ipfs_hash`
+    pub document_schema_version: u16,
+    pub thought_hash: [u8; 32],
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub votes_abstain: u64,
+    /// Per-option tallies of a proposal with more than two options; binary
+    /// proposals keep using `votes_for` / `votes_against`
+    #[max_len(MAX_PROPOSAL_OPTIONS as usize)]
+    pub option_votes: Vec<u64>,
+    /// Option that won by plurality, set when the proposal succeeds
+    pub winning_option: Option<u8>,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    /// Estimated unix time of `start_slot`
+    pub start_ts: i64,
+    /// Estimated unix time of `end_slot`, re-estimated whenever it moves
+    pub end_ts: i64,
+    pub status: ProposalStatus,
+    pub created_at: i64,
+    pub executed_at: i64,
+    /// Slot at which the proposal was finalized as `Succeeded`
+    pub queued_at_slot: u64,
+    /// Position in the execution queue, assigned when it first succeeds
+    pub finalize_order: Option<u64>,
+    pub voter_count: u64,
+    /// Tally weight applied by everyone who has voted, in the same units as
+    /// the vote buckets
+    pub total_applied_power: u64,
+    /// Votes bought with credits rather than cast from registered power.
+    /// Withdrawals leave it untouched, which only overstates what credits bought.
+    pub credit_votes: u64,
+    /// `total_voting_power` when voting opened
+    pub total_power_snapshot: u64,
+    pub snapshot_slot: u64,
+    pub vote_merkle_root: Option<[u8; 32]>,
+    /// Running hash over the proposal's vote stream, see `chain_vote`
+    pub vote_hash_chain: [u8; 32],
+    #[max_len(MAX_DELIBERATIONS)]
+    pub deliberations: Vec<DeliberationRef>,
+    pub veto_power_accumulated: u64,
+    pub veto_overridden: bool,
+    /// Set by a guardian freeze; blocks voting, finalization and execution
+    pub is_frozen: bool,
+    /// Slot the current freeze began, 0 when not frozen
+    pub frozen_at_slot: u64,
+    pub cancel_reason: Option<CancelReason>,
+    pub execution_attempts: u8,
+    pub num_options: u8,
+    pub execution_condition: Option<ExecutionCondition>,
+    pub execution_target: Option<Pubkey>,
+    /// Instruction data invoked on `execution_target` at execution
+    #[max_len(MAX_INSTRUCTION_DATA_LEN)]
+    pub instruction_data: Vec<u8>,
+    pub category: u8,
+    pub tiebreak_winner: Option<u8>,
+    pub tiebreak_seed: Option<[u8; 32]>,
+    pub defeat_reason: Option<DefeatReason>,
+    pub epi_override_approvals: u64,
+    /// Times voting has been extended by a late lead-changing vote
+    pub extension_count: u8,
+    pub is_ratification: bool,
+    pub ratified_action_slot: u64,
+    pub ratification_result: Option<bool>,
+    /// Earlier proposal this one replaces
+    pub supersedes: Option<u64>,
+    /// Submitted by a guardian under the emergency voting period and quorum
+    pub is_emergency: bool,
+    /// Ballots rank the options and the count runs instant-runoff rounds
+    pub is_ranked: bool,
+    /// Instant-runoff rounds started so far
+    pub current_round: u8,
+    /// Bit `i` set once option `i` is eliminated; fits `MAX_PROPOSAL_OPTIONS`
+    pub eliminated_options: u8,
+    /// Option whose ballots are still being transferred this round
+    pub eliminating_option: Option<u8>,
+    /// Ranked ballots left with no continuing choice
+    pub exhausted_votes: u64,
+    /// Instant-runoff has settled and the proposal may be finalized
+    pub ranked_tally_complete: bool,
+    /// Receives `amount` lamports from the treasury on execution
+    pub recipient: Option<Pubkey>,
+    pub amount: u64,
+    pub bump: u8,
+}
+
+impl Proposal {
+    /// Reset a newly created account to the state every submission path
+    /// starts from: the next proposal id, an active binary proposal voting
+    /// from now for `voting_period`, power snapshotted now, and every tally,
+    /// text and lifecycle field empty. Callers then set what their path adds.
+    pub fn init_from(
+        &mut self,
+        governance: &Governance,
+        proposer: Pubkey,
+        clock: &Clock,
+        bump: u8,
+    ) -> Result<()> {
+        self.id = governance.proposal_count;
+        self.proposer = proposer;
+        self.deposit = 0;
+        self.deposit_settled = false;
+        self.title = String::new();
+        self.description = String::new();
+        self.epi_score = 0;
+        self.profit_score = 0;
+        self.ethics_score = 0;
+        self.sub_scores = Vec::new();
+        self.composite_epi = 0;
+        self.epi_threshold = governance.epi_threshold;
+        self.ipfs_hash = [0u8; 32];
+        self.document_schema_version = 0;
+        self.thought_hash = [0u8; 32];
+        self.votes_for = 0;
+        self.votes_against = 0;
+        self.votes_abstain = 0;
+        self.option_votes = Vec::new();
+        self.winning_option = None;
+        self.schedule_voting(clock.slot, governance.voting_period, clock)?;
+        self.status = ProposalStatus::Active;
+        self.created_at = clock.unix_timestamp;
+        self.executed_at = 0;
+        self.queued_at_slot = 0;
+        self.finalize_order = None;
+        self.voter_count = 0;
+        self.total_applied_power = 0;
+        self.credit_votes = 0;
+        self.total_power_snapshot = governance.effective_total_power();
+        self.snapshot_slot = clock.slot;
+        self.vote_merkle_root = None;
+        self.vote_hash_chain = [0; 32];
+        self.deliberations = Vec::new();
+        self.veto_power_accumulated = 0;
+        self.veto_overridden = false;
+        self.is_frozen = false;
+        self.frozen_at_slot = 0;
+        self.cancel_reason = None;
+        self.execution_attempts = 0;
+        self.category = 0;
+        self.num_options = 2;
+        self.execution_condition = None;
+        self.execution_target = None;
+        self.instruction_data = Vec::new();
+        self.tiebreak_winner = None;
+        self.tiebreak_seed = None;
+        self.defeat_reason = None;
+        self.epi_override_approvals = 0;
+        self.extension_count = 0;
+        self.is_ranked = false;
+        self.current_round = 0;
+        self.eliminated_options = 0;
+        self.eliminating_option = None;
+        self.exhausted_votes = 0;
+        self.ranked_tally_complete = false;
+        self.is_ratification = false;
+        self.ratified_action_slot = 0;
+        self.ratification_result = None;
+        self.supersedes = None;
+        self.is_emergency = false;
+        self.recipient = None;
+        self.amount = 0;
+        self.bump = bump;
+        Ok(())
+    }
+
+    /// Open voting at `start_slot` for `voting_period` slots
+    pub fn schedule_voting(
+        &mut self,
+        start_slot: u64,
+        voting_period: u64,
+        clock: &Clock,
+    ) -> Result<()> {
+        self.start_slot = start_slot;
+        self.end_slot = voting_end_slot(start_slot, voting_period)?;
+        self.start_ts = slot_timestamp(clock, self.start_slot);
+        self.end_ts = slot_timestamp(clock, self.end_slot);
+        Ok(())
+    }
+
+    /// Fold one vote event into `vote_hash_chain`
+    ///
+    /// The chain starts at 32 zero bytes and each event sets it to
+    /// `sha256(chain || leaf)`, where `leaf` is `VoteRecord::leaf()` of the
+    /// record as written: `sha256(voter || proposal_id as u64 LE || support as
+    /// u8 || voting_power as u64 LE)`. Casts (`VoteCast`, `CreditsSpent`) and
+    /// changes (`VoteChanged`, with the new support) append the record's leaf;
+    /// withdrawals (`VoteWithdrawn`) append `VoteRecord::withdrawal_leaf()`,
+    /// the same leaf with zero power. Replaying a proposal's vote events in
+    /// log order must reproduce the value in `ProposalFinalized`; an event's
+    /// `VoteSupport` maps back to the raw `support` byte, with `Abstain` being
+    /// `abstain_option()`.
+    pub fn chain_vote(&mut self, leaf: &[u8; 32]) {
+        self.vote_hash_chain = hashv(&[&self.vote_hash_chain, leaf]).to_bytes();
+    }
+
+    /// Whether the proposal can no longer change status
+    pub fn is_terminal(&self) -> bool {
+        match self.status {
+            ProposalStatus::Executed
+            | ProposalStatus::Defeated
+            | ProposalStatus::Vetoed
+            | ProposalStatus::Cancelled
+            | ProposalStatus::ExecutionFailed => true,
+            ProposalStatus::Succeeded => self.is_ratification,
+            ProposalStatus::Active | ProposalStatus::PendingEpiOverride => false,
+        }
+    }
+
+    /// Whether the proposal passed: executed, or an approved ratification
+    pub fn is_passed(&self) -> bool {
+        self.status == ProposalStatus::Executed || self.ratification_result == Some(true)
+    }
+
+    /// Whether votes are tallied in `votes_for` / `votes_against` rather
+    /// than `option_votes`
+    pub fn is_binary(&self) -> bool {
+        self.num_options <= 2
+    }
+
+    /// Support value that abstains: one past the last option, so 2 on a
+    /// binary proposal
+    pub fn abstain_option(&self) -> u8 {
+        self.num_options
+    }
+
+    /// Tally of `option`; binary options follow vote support encoding
+    /// (0 = against, 1 = for)
+    pub fn option_tally(&self, option: u8) -> u64 {
+        if self.is_binary() {
+            return match option {
+                0 => self.votes_against,
+                1 => self.votes_for,
+                _ => 0,
+            };
+        }
+        self.option_votes.get(option as usize).copied().unwrap_or(0)
+    }
+
+    /// Votes cast for any option, abstentions excluded. Exhausted ranked
+    /// ballots still count, so turnout holds steady across runoff rounds.
+    pub fn option_turnout(&self) -> u128 {
+        (0..self.num_options)
+            .map(|option| self.option_tally(option) as u128)
+            .sum::<u128>()
+            + self.exhausted_votes as u128
+    }
+
+    /// Whether instant-runoff has eliminated `option`
+    pub fn is_eliminated(&self, option: u8) -> bool {
+        self.eliminated_options & (1 << option) != 0
+    }
+
+    /// Option the next instant-runoff round eliminates, or `None` once the
+    /// count is settled: at most two options continue, or one holds a strict
+    /// majority of the continuing votes. The fewest votes go first, a tie
+    /// eliminating the highest index.
+    pub fn next_elimination(&self) -> Option<u8> {
+        let continuing: Vec<u8> = (0..self.num_options)
+            .filter(|&option| !self.is_eliminated(option))
+            .collect();
+        if continuing.len() <= 2 {
+            return None;
+        }
+        let total: u128 = continuing
+            .iter()
+            .map(|&option| self.option_tally(option) as u128)
+            .sum();
+        let top = continuing
+            .iter()
+            .map(|&option| self.option_tally(option))
+            .max()
+            .unwrap_or(0);
+        if top as u128 * 2 > total {
+            return None;
+        }
+        continuing
+            .into_iter()
+            .min_by_key(|&option| (self.option_tally(option), std::cmp::Reverse(option)))
+    }
+
+    /// Options sharing the highest tally, in index order
+    pub fn leading_options(&self) -> Vec<u8> {
+        let top = (0..self.num_options)
+            .map(|option| self.option_tally(option))
+            .max()
+            .unwrap_or(0);
+        (0..self.num_options)
+            .filter(|&option| self.option_tally(option) == top)
+            .collect()
+    }
+
+    /// Votes behind the proposal: `votes_for` when binary, otherwise the
+    /// tally of the leading option
+    pub fn support_votes(&self) -> u64 {
+        if self.is_binary() {
+            return self.votes_for;
+        }
+        self.option_votes.iter().copied().max().unwrap_or(0)
+    }
+
+    /// Decode a `support` value, rejecting anything out of range
+    pub fn vote_support(&self, support: u8) -> Result<VoteSupport> {
+        if support == self.abstain_option() {
+            return Ok(VoteSupport::Abstain);
+        }
+        match support {
+            0 if self.is_binary() => Ok(VoteSupport::Against),
+            1 if self.is_binary() => Ok(VoteSupport::For),
+            option if option < self.num_options => Ok(VoteSupport::Option(option)),
+            _ => Err(GovernanceError::InvalidVoteType.into()),
+        }
+    }
+
+    /// Support as recorded in voter stats: a vote for one of several options
+    /// counts as neither for nor against
+    pub fn stance(&self, support: u8) -> u8 {
+        if self.is_binary() || support == self.abstain_option() {
+            return support.min(2);
+        }
+        2
+    }
+
+    /// Whether the deposit is forfeited rather than refunded: the proposal was
+    /// vetoed, cancelled, or defeated with `support_votes` under
+    /// `DEPOSIT_FORFEIT_SUPPORT_BPS` of all votes
+    pub fn forfeits_deposit(&self) -> bool {
+        match self.status {
+            ProposalStatus::Vetoed | ProposalStatus::Cancelled => return true,
+            ProposalStatus::Defeated => {}
+            _ => return false,
+        }
+        let total_votes = self.option_turnout() + self.votes_abstain as u128;
+        let support_bps = (self.support_votes() as u128 * 10000)
+            .checked_div(total_votes)
+            .unwrap_or(0);
+        support_bps < DEPOSIT_FORFEIT_SUPPORT_BPS as u128
+    }
+
+    /// PDA of the proposal with the given id
+    pub fn address(proposal_id: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"proposal", proposal_id.to_le_bytes().as_ref()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Count a new voter and the registered power they brought
+    pub fn record_voter(&mut self, voting_power: u64) -> Result<()> {
+        self.voter_count = self
+            .voter_count
+            .checked_add(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.total_applied_power = self
+            .total_applied_power
+            .checked_add(voting_power)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+
+    /// Undo `record_voter` for a withdrawn vote
+    pub fn remove_voter(&mut self, voting_power: u64) -> Result<()> {
+        self.voter_count = self
+            .voter_count
+            .checked_sub(1)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        self.total_applied_power = self
+            .total_applied_power
+            .checked_sub(voting_power)
+            .ok_or(GovernanceError::ArithmeticOverflow)?;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoterAccount {
+    pub voter: Pubkey,
+    pub voting_power: u64,
+    /// Voter this account's power is delegated to
+    pub delegate: Option<Pubkey>,
+    /// Power other voters have delegated to this account
+    pub delegated_power: u64,
+    pub credit_budget: u64,
+    pub notification_prefs: u8,
+    pub total_votes_cast: u64,
+    pub proposals_voted_for: u64,
+    pub proposals_voted_against: u64,
+    pub last_vote_slot: u64,
+    pub registered_at: i64,
+    /// First proposal id created after registration; earlier ones were never
+    /// open to this voter
+    pub first_eligible_proposal: u64,
+    /// Slot of this voter's most recent proposal submission
+    pub last_proposal_slot: u64,
+    pub proposals_submitted: u64,
+    /// Last `end_slot` among proposals this voter has voted on; registered
+    /// power cannot change or be withdrawn until voting on all of them ends
+    pub locked_until_slot: u64,
+    pub bump: u8,
+}
+
+impl VoterAccount {
+    /// A freshly registered voter with no history
+    pub fn new(
+        voter: Pubkey,
+        voting_power: u64,
+        registered_at: i64,
+        first_eligible_proposal: u64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            voter,
+            voting_power,
+            delegate: None,
+            delegated_power: 0,
+            credit_budget: 0,
+            notification_prefs: 0,
+            total_votes_cast: 0,
+            proposals_voted_for: 0,
+            proposals_voted_against: 0,
+            last_vote_slot: 0,
+            registered_at,
+            first_eligible_proposal,
+            last_proposal_slot: 0,
+            proposals_submitted: 0,
+            locked_until_slot: 0,
+            bump,
+        }
+    }
+
+    /// PDA of the given voter's account
+    pub fn address(voter: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"voter", voter.as_ref()], &crate::ID).0
+    }
+
+    /// Own power plus everything delegated to this voter
+    pub fn effective_voting_power(&self) -> Result<u64> {
+        self.voting_power
+            .checked_add(self.delegated_power)
+            .ok_or_else(|| GovernanceError::ArithmeticOverflow.into())
+    }
+
+    /// Update participation stats for a newly cast vote
+    pub fn record_vote(&mut self, support: u8, slot: u64) {
+        self.total_votes_cast += 1;
+        match support {
+            0 => self.proposals_voted_against += 1,
+            1 => self.proposals_voted_for += 1,
+            _ => {}
+        }
+        self.last_vote_slot = slot;
+    }
+
+    /// Keep registered power locked until voting ends at `end_slot`
+    pub fn lock_until(&mut self, end_slot: u64) {
+        self.locked_until_slot = self.locked_until_slot.max(end_slot);
+    }
+
+    /// Whether registered power is locked by an open vote; `end_slot` is
+    /// inclusive, so the lock lifts the slot after
+    pub fn is_power_locked(&self, slot: u64) -> bool {
+        slot <= self.locked_until_slot
+    }
+
+    /// Proposals created since this voter registered
+    pub fn proposals_eligible(&self, governance: &Governance) -> u64 {
+        governance
+            .proposal_count
+            .saturating_sub(self.first_eligible_proposal)
+    }
+
+    /// Participation snapshot for `VoterParticipationUpdated`
+    pub fn participation(
+        &self,
+        governance: &Governance,
+        timestamp: i64,
+    ) -> VoterParticipationUpdated {
+        VoterParticipationUpdated {
+            voter: self.voter,
+            votes_cast: self.total_votes_cast,
+            last_voted_slot: self.last_vote_slot,
+            proposals_eligible: self.proposals_eligible(governance),
+            timestamp,
+        }
+    }
+
+    /// Move a previously recorded vote between the for/against counters
+    pub fn change_vote(&mut self, old_support: u8, new_support: u8, slot: u64) {
+        match old_support {
+            0 => self.proposals_voted_against = self.proposals_voted_against.saturating_sub(1),
+            1 => self.proposals_voted_for = self.proposals_voted_for.saturating_sub(1),
+            _ => {}
+        }
+        match new_support {
+            0 => self.proposals_voted_against += 1,
+            1 => self.proposals_voted_for += 1,
+            _ => {}
+        }
+        self.last_vote_slot = slot;
+    }
+
+    /// Remove a withdrawn vote from the participation stats
+    pub fn withdraw_vote(&mut self, support: u8, slot: u64) {
+        self.total_votes_cast = self.total_votes_cast.saturating_sub(1);
+        match support {
+            0 => self.proposals_voted_against = self.proposals_voted_against.saturating_sub(1),
+            1 => self.proposals_voted_for = self.proposals_voted_for.saturating_sub(1),
+            _ => {}
+        }
+        self.last_vote_slot = slot;
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub voter: Pubkey,
+    pub proposal_id: u64,
+    pub support: u8,
+    pub voting_power: u64,
+    pub timestamp: i64,
+    #[max_len(MAX_VOTE_REASON_LEN)]
+    pub reason: String,
+    /// Ranked ballot choices, most preferred first; empty otherwise
+    #[max_len(MAX_PROPOSAL_OPTIONS as usize)]
+    pub rankings: Vec<u8>,
+    /// Tally the ballot currently counts toward: `support` until a runoff
+    /// round moves it, `EXHAUSTED_BALLOT` once nothing it ranked remains
+    pub counted_option: u8,
+    pub bump: u8,
+}
+
+impl VoteRecord {
+    /// PDA of `voter`'s vote record on the given proposal
+    pub fn address(proposal_id: u64, voter: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(
+            &[b"vote", proposal_id.to_le_bytes().as_ref(), voter.as_ref()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    /// Merkle leaf committing to this vote, as expected by `seal_proposal`
+    pub fn leaf(&self) -> [u8; 32] {
+        hashv(&[
+            self.voter.as_ref(),
+            &self.proposal_id.to_le_bytes(),
+            &[self.support],
+            &self.voting_power.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    /// Leaf recording the withdrawal of this vote: `leaf()` with zero power.
+    /// Cast votes always carry power, so the two cannot be confused.
+    pub fn withdrawal_leaf(&self) -> [u8; 32] {
+        hashv(&[
+            self.voter.as_ref(),
+            &self.proposal_id.to_le_bytes(),
+            &[self.support],
+            &0u64.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GuardianAccount {
+    pub guardian: Pubkey,
+    pub is_active: bool,
+    /// Weight each of this guardian's vetoes adds toward the veto thresholds
+    pub veto_weight: u64,
+    pub veto_count: u64,
+    /// Proposal this guardian most recently vetoed
+    pub last_vetoed_proposal: Option<u64>,
+    /// Slot of this guardian's most recent veto
+    pub last_veto_slot: u64,
+    /// Justification given for that veto
+    #[max_len(MAX_VETO_REASON_LEN)]
+    pub last_veto_reason: String,
+    pub veto_delegate: Option<VetoDelegation>,
+    /// Veto activity that decays over time, unlike the all-time `veto_count`
+    pub recent_veto_score: u64,
+    pub veto_score_updated_slot: u64,
+    pub added_at: i64,
+    /// Unix time after which this guardian can no longer veto
+    pub term_end: i64,
+    pub bump: u8,
+}
+
+impl GuardianAccount {
+    /// Fail unless this guardian is active and within their term at `now`
+    pub fn require_serving(&self, now: i64) -> Result<()> {
+        require!(self.is_active, GovernanceError::NotGuardian);
+        require!(now <= self.term_end, GovernanceError::GuardianTermExpired);
+        Ok(())
+    }
+
+    /// Recent veto score after decaying whole windows elapsed since the last
+    /// update, paired with the slot that decay is accounted up to.
+    pub fn decayed_veto_score(&self, governance: &Governance, slot: u64) -> (u64, u64) {
+        let window = governance.veto_decay_window;
+        if window == 0 {
+            return (self.recent_veto_score, self.veto_score_updated_slot);
+        }
+        let windows = slot.saturating_sub(self.veto_score_updated_slot) / window;
+        let decay = windows.saturating_mul(governance.veto_decay_per_window);
+        (
+            self.recent_veto_score.saturating_sub(decay),
+            self.veto_score_updated_slot + windows * window,
+        )
+    }
+
+    /// Persist decay up to `slot`, keeping any partial window's progress.
+    pub fn apply_veto_decay(&mut self, governance: &Governance, slot: u64) {
+        let (score, updated_slot) = self.decayed_veto_score(governance, slot);
+        self.recent_veto_score = score;
+        self.veto_score_updated_slot = updated_slot;
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct EpiOverrideApproval {
+    pub guardian: Pubkey,
+    pub proposal_id: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// Holds proposal deposits until they are refunded or forfeited
+#[account]
+#[derive(InitSpace)]
+pub struct DepositVault {
+    pub bump: u8,
+}
+
+/// DAO funds that executed proposals disburse to their recipients
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GuardianVetoRecord {
+    pub guardian: Pubkey,
+    pub proposal_id: u64,
+    /// Ethics score the guardian cited to justify the veto
+    pub ethics_justification_score: u64,
+    #[max_len(MAX_VETO_REASON_LEN)]
+    pub reason: String,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// A submission attempt that failed the EPI gate
+#[account]
+#[derive(InitSpace)]
+pub struct RejectedProposalLog {
+    pub rejection_id: u64,
+    pub proposer: Pubkey,
+    pub epi_score: u64,
+    pub epi_threshold: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// A proposer's request to re-enter the EPI gate with revised scores
+#[account]
+#[derive(InitSpace)]
+pub struct EpiAppeal {
+    pub appeal_id: u64,
+    pub proposer: Pubkey,
+    #[max_len(MAX_TITLE_LEN as usize)]
+    pub title: String,
+    #[max_len(MAX_DESCRIPTION_LEN as usize)]
+    pub description: String,
+    pub profit_score: u64,
+    pub ethics_score: u64,
+    /// EPI score derived from the revised inputs
+    pub epi_score: u64,
+    /// Hash of the off-chain evidence supporting the revised scores
+    pub evidence_hash: [u8; 32],
+    pub ipfs_hash: [u8; 32],
+    pub thought_hash: [u8; 32],
+    pub category: u8,
+    /// Guardian that approved the appeal
+    pub resolved_by: Option<Pubkey>,
+    /// Proposal created on approval
+    pub proposal_id: Option<u64>,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+/// Claims a thought hash for the proposal that first used it
+#[account]
+#[derive(InitSpace)]
+pub struct ThoughtHashRegistry {
+    pub proposal_id: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ThoughtLog {
+    pub proposal_id: u64,
+    #[max_len(32)]
+    pub agent_id: String,
+    #[max_len(64)]
+    pub action: String,
+    pub epi_score: u64,
+    pub reasoning_hash: [u8; 32],
+    pub inputs_hash: [u8; 32],
+    pub outputs_hash: [u8; 32],
+    pub timestamp: i64,
+    pub logger: Pubkey,
+    pub bump: u8,
+}
+
+/// Oracle check gating execution. The oracle account must hold the value as a
+/// little-endian `i64` at `value_offset` bytes into its data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct ExecutionCondition {
+    pub oracle: Pubkey,
+    pub value_offset: u32,
+    pub operator: u8,
+    pub threshold: i64,
+}
+
+impl ExecutionCondition {
+    /// Read the oracle value from `data` and compare it against `threshold`
+    pub fn evaluate(&self, data: &[u8]) -> Result<bool> {
+        let start = self.value_offset as usize;
+        let bytes: [u8; 8] = data
+            .get(start..start + 8)
+            .and_then(|slice| slice.try_into().ok())
+            .ok_or(GovernanceError::InvalidOracleAccount)?;
+        let value = i64::from_le_bytes(bytes);
+
+        Ok(match self.operator {
+            CONDITION_GT => value > self.threshold,
+            CONDITION_GTE => value >= self.threshold,
+            CONDITION_LT => value < self.threshold,
+            CONDITION_LTE => value <= self.threshold,
+            CONDITION_EQ => value == self.threshold,
+            _ => return Err(GovernanceError::InvalidConditionOperator.into()),
+        })
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub struct DeliberationRef {
+    pub ipfs_hash: [u8; 32],
+    #[max_len(128)]
+    pub summary: String,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct VetoDelegation {
+    pub deputy: Pubkey,
+    pub expires_at_slot: u64,
+}
+
+/// One entry of `register_voters_batch`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct VoterRegistration {
+    pub voter: Pubkey,
+    pub voting_power: u64,
+}
+
+/// Arguments of `initialize`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeParams {
+    pub epi_threshold: u64,
+    pub voting_period: u64,
+    pub quorum_bps: u64,
+    pub execution_delay: u64,
+    pub voting_mode: u8,
+    pub proposal_deposit: u64,
+    pub profit_weight: u64,
+    pub ethics_weight: u64,
+    pub abstain_counts_for_quorum: bool,
+    pub max_title_len: u16,
+    pub max_description_len: u16,
+}
+
+/// Arguments of `submit_proposal`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposalParams {
+    pub title: String,
+    pub description: String,
+    pub profit_score: u64,
+    pub ethics_score: u64,
+    pub ipfs_hash: [u8; 32],
+    pub thought_hash: [u8; 32],
+    pub extra_sub_scores: Vec<SubScore>,
+    pub category: u8,
+    pub num_options: u8,
+    pub ranked: bool,
+    pub execution_condition: Option<ExecutionCondition>,
+    pub execution_target: Option<Pubkey>,
+    pub instruction_data: Vec<u8>,
+    pub document_schema_version: u16,
+    pub min_epi_override: Option<u64>,
+    pub start_delay: u64,
+    pub supersedes: Option<u64>,
+    pub recipient: Option<Pubkey>,
+    pub amount: u64,
+}
+
+/// Arguments of `appeal_epi`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AppealParams {
+    pub title: String,
+    pub description: String,
+    pub profit_score: u64,
+    pub ethics_score: u64,
+    pub evidence_hash: [u8; 32],
+    pub ipfs_hash: [u8; 32],
+    pub thought_hash: [u8; 32],
+    pub category: u8,
+}
+
+/// Arguments of `log_thought` besides the proposal it refers to
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ThoughtParams {
+    pub agent_id: String,
+    pub action: String,
+    pub epi_score: u64,
+    pub reasoning_hash: [u8; 32],
+    pub inputs_hash: [u8; 32],
+    pub outputs_hash: [u8; 32],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct SubScore {
+    pub dimension: u8,
+    pub value: u64,
+}
+
+/// Borsh stores the variant index, so existing proposals depend on this order.
+/// Only ever append new variants; the assertions below fail the build otherwise.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ProposalStatus {
+    Active,
+    Defeated,
+    Succeeded,
+    Executed,
+    Vetoed,
+    Cancelled,
+    ExecutionFailed,
+    PendingEpiOverride,
+}
+
+const _: () = {
+    assert!(ProposalStatus::Active as u8 == 0);
+    assert!(ProposalStatus::Defeated as u8 == 1);
+    assert!(ProposalStatus::Succeeded as u8 == 2);
+    assert!(ProposalStatus::Executed as u8 == 3);
+    assert!(ProposalStatus::Vetoed as u8 == 4);
+    assert!(ProposalStatus::Cancelled as u8 == 5);
+    assert!(ProposalStatus::ExecutionFailed as u8 == 6);
+    assert!(ProposalStatus::PendingEpiOverride as u8 == 7);
+};
+
+/// Decoded vote `support`. Instructions keep taking the `u8` encoding
+/// (0 = against, 1 = for, 2 = abstain on a binary proposal); the first three
+/// variants share those discriminants.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VoteSupport {
+    Against,
+    For,
+    Abstain,
+    /// Index of the chosen option on a multi-option proposal
+    Option(u8),
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DefeatReason {
+    QuorumNotReached,
+    VotesAgainst,
+    MarginTooNarrow,
+    QuorumMathematicallyUnreachable,
+    Tied,
+    TurnoutNotReached,
+}
+
+impl From<DefeatReason> for Error {
+    fn from(reason: DefeatReason) -> Self {
+        match reason {
+            DefeatReason::QuorumNotReached | DefeatReason::QuorumMathematicallyUnreachable => {
+                GovernanceError::QuorumNotReached.into()
+            }
+            DefeatReason::VotesAgainst => GovernanceError::ProposalNotPassed.into(),
+            DefeatReason::MarginTooNarrow => GovernanceError::MarginTooNarrow.into(),
+            DefeatReason::Tied => GovernanceError::ProposalTied.into(),
+            DefeatReason::TurnoutNotReached => GovernanceError::TurnoutNotReached.into(),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum CancelReason {
+    ProposerDisqualified,
+    WithdrawnByProposer,
+    Superseded,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ParticipationMetrics {
+    pub proposal_count: u64,
+    pub avg_turnout_bps: u64,
+    pub pass_rate_bps: u64,
+    pub veto_rate_bps: u64,
+    pub avg_voter_count: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct VoterStats {
+    pub voter: Pubkey,
+    pub total_votes_cast: u64,
+    pub proposals_voted_for: u64,
+    pub proposals_voted_against: u64,
+    pub last_vote_slot: u64,
+    /// Proposals created since the voter registered
+    pub proposals_eligible: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct VoterSummary {
+    pub voter: Pubkey,
+    pub voting_power: u64,
+    /// Power other voters have delegated to this voter
+    pub delegated_power: u64,
+    /// Voter this voter's own power is delegated to
+    pub delegate: Option<Pubkey>,
+    pub registered_at: i64,
+    pub votes_cast: u64,
+    pub last_voted_slot: u64,
+    /// Proposals created since the voter registered
+    pub proposals_eligible: u64,
+    pub locked_until_slot: u64,
+}
+
+/// Client-facing lifecycle phase, derived rather than stored
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProposalPhase {
+    /// Submitted but voting has not started yet
+    Draft,
+    /// Awaiting guardian approval of a below-threshold EPI score
+    PendingApproval,
+    Voting,
+    /// Voting closed; awaiting finalization
+    VotingEnded,
+    /// Ratification approved
+    Succeeded,
+    InTimelock,
+    /// Passed, but guardians may still veto until the veto window closes
+    VetoWindow,
+    Executable,
+    Executed,
+    Defeated,
+    Vetoed,
+    Cancelled,
+    /// Execution attempts exhausted
+    Expired,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ProposalPhaseInfo {
+    pub phase: ProposalPhase,
+    pub slots_until_next_phase: Option<u64>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ProposalResult {
+    pub quorum_reached: bool,
+    pub passed: bool,
+    /// Turnout counted toward quorum
+    pub total_votes: u64,
+    pub quorum_votes: u64,
+    pub status: ProposalStatus,
+}
+
+/// Per-guard breakdown returned by `check_executable`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutableStatus {
+    /// Every guard below passes
+    pub executable: bool,
+    pub paused: bool,
+    /// Status is `Succeeded` and the proposal is not a ratification
+    pub succeeded: bool,
+    pub frozen: bool,
+    /// The execution queue has reached this proposal
+    pub in_order: bool,
+    pub timelock_elapsed: bool,
+    /// Quorum holds, against live voting power once the snapshot is stale
+    pub quorum_holds: bool,
+    pub passed: bool,
+    pub target_allowed: bool,
+    /// First slot the timelock allows execution
+    pub executable_at: u64,
+}
+
+// ============ Events ============
+
+#[event]
+pub struct GovernanceInitialized {
+    pub authority: Pubkey,
+    pub epi_threshold: u64,
+    pub voting_period: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalClosed {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub status: ProposalStatus,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalSubmitted {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub title: String,
+    pub epi_score: u64,
+    pub category: u8,
+    pub start_slot: u64,
+    pub end_slot: u64,
+    /// Estimated unix time voting ends
+    pub end_ts: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EPIRejection {
+    pub rejection_id: u64,
+    pub proposer: Pubkey,
+    pub epi_score: u64,
+    pub epi_threshold: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpiAppealSubmitted {
+    pub appeal_id: u64,
+    pub proposer: Pubkey,
+    pub profit_score: u64,
+    pub ethics_score: u64,
+    pub epi_score: u64,
+    pub evidence_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpiAppealResolved {
+    pub appeal_id: u64,
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub epi_score: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EmergencyProposalSubmitted {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub title: String,
+    pub category: u8,
+    pub end_slot: u64,
+    pub end_ts: i64,
+    pub quorum_bps: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RatificationSubmitted {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub title: String,
+    pub executed_at_slot: u64,
+    pub end_slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RatificationFinalized {
+    pub proposal_id: u64,
+    pub approved: bool,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EpiOverrideApproved {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub approvals: u64,
+    pub threshold: u64,
+    pub activated: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub support: VoteSupport,
+    pub voting_power: u64,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteChanged {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub old_support: VoteSupport,
+    pub new_support: VoteSupport,
+    pub voting_power: u64,
+    pub reason: String,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoteWithdrawn {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub support: VoteSupport,
+    pub voting_power: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsSpent {
+    pub proposal_id: u64,
+    pub voter: Pubkey,
+    pub support: VoteSupport,
+    pub votes: u64,
+    pub cost: u64,
+    pub remaining_budget: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExecutionQueueAdvanced {
+    pub skipped_proposal_id: u64,
+    pub execution_sequence: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalFrozen {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalUnfrozen {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub frozen_slots: u64,
+    pub end_slot: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal_id: u64,
+    pub executor: Pubkey,
+    pub votes_for: u64,
+    pub votes_against: u64,
+    /// Option that won by plurality; 1 (`for`) on a binary proposal
+    pub winning_option: u8,
+    /// Whether the proposal's instruction payload was invoked; a failed
+    /// invocation aborts the transaction, so `true` means it succeeded
+    pub payload_invoked: bool,
+    /// Lamports sent from the treasury to the proposal's recipient
+    pub disbursed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RankedRoundStarted {
+    pub proposal_id: u64,
+    pub round: u8,
+    pub eliminated_option: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RankedTallyCompleted {
+    pub proposal_id: u64,
+    pub rounds: u8,
+    pub exhausted_votes: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalFinalized {
+    pub proposal_id: u64,
+    pub status: ProposalStatus,
+    /// Final `Proposal::vote_hash_chain`, committing to every vote event
+    pub vote_hash_chain: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ProposalDefeated {
+    pub proposal_id: u64,
+    pub reason: DefeatReason,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TieResolved {
+    pub proposal_id: u64,
+    pub winner: u8,
+    pub seed: [u8; 32],
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct ExecutionAttemptFailed {
+    pub proposal_id: u64,
+    pub attempts: u8,
+    pub max_attempts: u8,
+    pub exhausted: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct TreasuryDeposited {
+    pub depositor: Pubkey,
+    pub amount: u64,
+    /// Treasury balance after the deposit
+    pub balance: u64,
+    pub timestamp: i64,
+}
 
-        msg!("EPI threshold updated: {} -> {}", old_threshold, new_threshold);
-        Ok(())
-    }
+#[event]
+pub struct DepositSettled {
+    pub proposal_id: u64,
+    pub proposer: Pubkey,
+    pub amount: u64,
+    pub refunded: bool,
+    pub timestamp: i64,
 }
 
-// ============ Account Contexts ============
+#[event]
+pub struct GuardianVetoRecorded {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub accumulated: u64,
+    pub required: u64,
+    pub timestamp: i64,
+}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + Governance::INIT_SPACE,
-        seeds = [b"governance"],
-        bump
-    )]
-    pub governance: Account<'info, Governance>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct VetoOverridden {
+    pub proposal_id: u64,
+    pub votes_for: u64,
+    pub total_power_snapshot: u64,
+    pub status: ProposalStatus,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct SubmitProposal<'info> {
-    #[account(mut)]
-    pub governance: Account<'info, Governance>,
-    #[account(
-        init,
-        payer = proposer,
-        space = 8 + Proposal::INIT_SPACE,
-        seeds = [b"proposal", governance.proposal_count.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub proposal: Account<'info, Proposal>,
-    #[account(mut)]
-    pub proposer: Signer<'info>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct ProposalVetoed {
+    pub proposal_id: u64,
+    pub guardian: Pubkey,
+    pub reason: String,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-#[instruction(proposal_id: u64)]
-pub struct Vote<'info> {
-    pub governance: Account<'info, Governance>,
-    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
-    pub proposal: Account<'info, Proposal>,
-    #[account(seeds = [b"voter", voter.key().as_ref()], bump = voter_account.bump)]
-    pub voter_account: Account<'info, VoterAccount>,
-    #[account(
-        init,
-        payer = voter,
-        space = 8 + VoteRecord::INIT_SPACE,
-        seeds = [b"vote", proposal_id.to_le_bytes().as_ref(), voter.key().as_ref()],
-        bump
-    )]
-    pub vote_record: Account<'info, VoteRecord>,
-    #[account(mut)]
-    pub voter: Signer<'info>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct ProposalScoresCorrected {
+    pub proposal_id: u64,
+    pub old_epi_score: u64,
+    pub new_epi_score: u64,
+    pub profit_score: u64,
+    pub ethics_score: u64,
+    pub old_category: u8,
+    pub category: u8,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-#[instruction(proposal_id: u64)]
-pub struct ExecuteProposal<'info> {
-    pub governance: Account<'info, Governance>,
-    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
-    pub proposal: Account<'info, Proposal>,
-    pub executor: Signer<'info>,
+#[event]
+pub struct DeliberationAttached {
+    pub proposal_id: u64,
+    pub attached_by: Pubkey,
+    pub ipfs_hash: [u8; 32],
+    pub summary: String,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-#[instruction(proposal_id: u64)]
-pub struct VetoProposal<'info> {
-    pub governance: Account<'info, Governance>,
-    #[account(mut, seeds = [b"proposal", proposal_id.to_le_bytes().as_ref()], bump = proposal.bump)]
-    pub proposal: Account<'info, Proposal>,
-    #[account(mut, seeds = [b"guardian", guardian.key().as_ref()], bump = guardian_account.bump)]
-    pub guardian_account: Account<'info, GuardianAccount>,
-    pub guardian: Signer<'info>,
+#[event]
+pub struct ProposalCancelled {
+    pub proposal_id: u64,
+    pub canceler: Pubkey,
+    pub reason: CancelReason,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct LogThought<'info> {
-    #[account(
-        init,
-        payer = logger,
-        space = 8 + ThoughtLog::INIT_SPACE,
-        seeds = [b"thought", logger.key().as_ref(), &Clock::get()?.unix_timestamp.to_le_bytes()],
-        bump
-    )]
-    pub thought_log: Account<'info, ThoughtLog>,
-    #[account(mut)]
-    pub logger: Signer<'info>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct ProposalSuperseded {
+    pub proposal_id: u64,
+    pub superseded_by: u64,
+    /// Whether the superseded proposal was still active and got cancelled
+    pub cancelled: bool,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct RegisterVoter<'info> {
-    #[account(mut)]
-    pub governance: Account<'info, Governance>,
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + VoterAccount::INIT_SPACE,
-        seeds = [b"voter", voter.key().as_ref()],
-        bump
-    )]
-    pub voter_account: Account<'info, VoterAccount>,
-    /// CHECK: Voter being registered
-    pub voter: UncheckedAccount<'info>,
-    #[account(mut, constraint = authority.key() == governance.authority @ GovernanceError::Unauthorized)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct ProposalSealed {
+    pub proposal_id: u64,
+    pub vote_merkle_root: [u8; 32],
+    pub voter_count: u64,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct AddGuardian<'info> {
-    #[account(constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
-    pub governance: Account<'info, Governance>,
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + GuardianAccount::INIT_SPACE,
-        seeds = [b"guardian", new_guardian.key().as_ref()],
-        bump
-    )]
-    pub guardian_account: Account<'info, GuardianAccount>,
-    /// CHECK: New guardian
-    pub new_guardian: UncheckedAccount<'info>,
-    #[account(mut)]
-    pub authority: Signer<'info>,
-    pub system_program: Program<'info, System>,
+#[event]
+pub struct VoteDelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub voting_power: u64,
+    pub timestamp: i64,
 }
 
-#[derive(Accounts)]
-pub struct UpdateGovernance<'info> {
-    #[account(mut, constraint = governance.authority == authority.key() @ GovernanceError::Unauthorized)]
-    pub governance: Account<'info, Governance>,
-    pub authority: Signer<'info>,
+#[event]
+pub struct VoteUndelegated {
+    pub delegator: Pubkey,
+    pub delegate: Pubkey,
+    pub voting_power: u64,
+    pub timestamp: i64,
 }
 
-// ============ State Accounts ============
+#[event]
+pub struct VetoDelegated {
+    pub guardian: Pubkey,
+    pub deputy: Pubkey,
+    pub expires_at_slot: u64,
+    pub timestamp: i64,
+}
 
-#[account]
-#[derive(InitSpace)]
-pub struct Governance {
-    pub authority: Pubkey,
-    pub epi_threshold: u64,
-    pub voting_period: u64,
-    pub quorum_percentage: u64,
-    pub proposal_count: u64,
-    pub total_voting_power: u64,
-    pub is_paused: bool,
-    pub bump: u8,
+#[event]
+pub struct VetoDelegationRevoked {
+    pub guardian: Pubkey,
+    pub timestamp: i64,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct Proposal {
-    pub id: u64,
-    pub proposer: Pubkey,
-    #[max_len(64)]
-    pub title: String,
-    #[max_len(256)]
-    pub description: String,
-    pub epi_score: u64,
-    pub profit_score: u64,
-    pub ethics_score: u64,
-    pub ipfs_hash: [u8; 32],
+#[event]
+pub struct ThoughtLogged {
+    pub proposal_id: u64,
+    pub agent_id: String,
     pub thought_hash: [u8; 32],
-    pub votes_for: u64,
-    pub votes_against: u64,
-    pub votes_abstain: u64,
-    pub start_slot: u64,
-    pub end_slot: u64,
-    pub status: ProposalStatus,
-    pub created_at: i64,
-    pub executed_at: i64,
-    pub bump: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoterRegistered {
+    pub voter: Pubkey,
+    pub voting_power: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct VoterDeregistered {
+    pub voter: Pubkey,
+    pub voting_power: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditBudgetSet {
+    pub voter: Pubkey,
+    pub credit_budget: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct NotificationPrefsUpdated {
+    pub voter: Pubkey,
+    pub prefs: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianAdded {
+    pub guardian: Pubkey,
+    pub veto_weight: u64,
+    pub term_end: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianRenewed {
+    pub guardian: Pubkey,
+    pub term_end: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct GuardianRemoved {
+    pub guardian: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct EPIThresholdUpdated {
+    pub old_threshold: u64,
+    pub new_threshold: u64,
+    pub timestamp: i64,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct VoterAccount {
-    pub voter: Pubkey,
-    pub voting_power: u64,
-    pub registered_at: i64,
-    pub bump: u8,
+#[event]
+pub struct GovernancePauseToggled {
+    pub paused: bool,
+    pub timestamp: i64,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct VoteRecord {
-    pub voter: Pubkey,
-    pub proposal_id: u64,
-    pub support: u8,
-    pub voting_power: u64,
+#[event]
+pub struct SubmissionPauseToggled {
+    pub paused: bool,
     pub timestamp: i64,
-    pub bump: u8,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct GuardianAccount {
-    pub guardian: Pubkey,
-    pub is_active: bool,
-    pub veto_count: u64,
-    pub added_at: i64,
-    pub bump: u8,
+#[event]
+pub struct EpiDimensionsUpdated {
+    pub weights: [u64; MAX_EPI_DIMENSIONS],
+    pub floors: [u64; MAX_EPI_DIMENSIONS],
+    pub timestamp: i64,
 }
 
-#[account]
-#[derive(InitSpace)]
-pub struct ThoughtLog {
-    #[max_len(32)]
-    pub agent_id: String,
-    #[max_len(64)]
-    pub action: String,
-    pub epi_score: u64,
-    pub reasoning_hash: [u8; 32],
-    pub inputs_hash: [u8; 32],
-    pub outputs_hash: [u8; 32],
+#[event]
+pub struct ExecutionAuthorityUpdated {
+    pub category: Option<u8>,
+    pub execution_authority: Option<Pubkey>,
     pub timestamp: i64,
-    pub logger: Pubkey,
-    pub bump: u8,
 }
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
-pub enum ProposalStatus {
-    Active,
-    Defeated,
-    Succeeded,
-    Executed,
-    Vetoed,
-    Cancelled,
+#[event]
+pub struct ExecutionTargetUpdated {
+    pub program: Pubkey,
+    pub allowed: bool,
+    pub timestamp: i64,
 }
 
-// ============ Events ============
+#[event]
+pub struct VotingPeriodUpdated {
+    pub old_period: u64,
+    pub new_period: u64,
+    pub timestamp: i64,
+}
 
 #[event]
-pub struct GovernanceInitialized {
-    pub authority: Pubkey,
-    pub epi_threshold: u64,
-    pub voting_period: u64,
+pub struct AuthorityCouncilUpdated {
+    pub signer: Pubkey,
+    pub added: bool,
+    pub signer_count: usize,
+    pub threshold: u8,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ProposalSubmitted {
-    pub proposal_id: u64,
-    pub proposer: Pubkey,
-    pub title: String,
-    pub epi_score: u64,
-    pub start_slot: u64,
-    pub end_slot: u64,
+pub struct AuthorityTransferProposed {
+    pub current_authority: Pubkey,
+    pub pending_authority: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct VoteCast {
-    pub proposal_id: u64,
-    pub voter: Pubkey,
-    pub support: u8,
-    pub voting_power: u64,
-    pub reason: String,
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ProposalExecuted {
+pub struct ProposalAmended {
     pub proposal_id: u64,
-    pub executor: Pubkey,
-    pub votes_for: u64,
-    pub votes_against: u64,
+    pub title: String,
+    pub ipfs_hash: [u8; 32],
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ProposalVetoed {
-    pub proposal_id: u64,
-    pub guardian: Pubkey,
-    pub reason: String,
+pub struct VotingPowerUpdated {
+    pub voter: Pubkey,
+    pub old_power: u64,
+    pub new_power: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct ThoughtLogged {
-    pub proposal_id: u64,
-    pub agent_id: String,
-    pub thought_hash: [u8; 32],
+pub struct CategoryQuorumUpdated {
+    pub category: u8,
+    pub quorum: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct VoterRegistered {
+pub struct VoterParticipationUpdated {
     pub voter: Pubkey,
-    pub voting_power: u64,
+    pub votes_cast: u64,
+    pub last_voted_slot: u64,
+    pub proposals_eligible: u64,
     pub timestamp: i64,
 }
 
 #[event]
-pub struct GuardianAdded {
-    pub guardian: Pubkey,
-    pub timestamp: i64,
+pub struct VotingExtended {
+    pub proposal_id: u64,
+    pub end_slot: u64,
+    pub extension_count: u8,
+    pub slot: u64,
 }
 
 #[event]
-pub struct EPIThresholdUpdated {
-    pub old_threshold: u64,
-    pub new_threshold: u64,
+pub struct MinProposalPowerUpdated {
+    pub old_min_power: u64,
+    pub new_min_power: u64,
     pub timestamp: i64,
 }
 
@@ -652,12 +7880,902 @@ pub enum GovernanceError {
     NoVotingPower,
     #[msg("Invalid score value")]
     InvalidScore,
-    #[msg("Title too long (max 64 chars)")]
+    #[msg("Title exceeds the configured maximum length")]
     TitleTooLong,
-    #[msg("Description too long (max 256 chars)")]
+    #[msg("Description exceeds the configured maximum length")]
     DescriptionTooLong,
     #[msg("Agent ID too long (max 32 chars)")]
     AgentIdTooLong,
     #[msg("Action too long (max 64 chars)")]
     ActionTooLong,
+    #[msg("Vote leaf count does not match voter count")]
+    VoteCountMismatch,
+    #[msg("Proposal has already been sealed")]
+    ProposalAlreadySealed,
+    #[msg("Proposer voting power is below the minimum required")]
+    InsufficientProposalPower,
+    #[msg("Proposer still meets the minimum proposal power")]
+    ProposerStillQualified,
+    #[msg("Invalid EPI dimension")]
+    InvalidDimension,
+    #[msg("EPI dimension provided more than once")]
+    DuplicateDimension,
+    #[msg("EPI dimension score is below its floor")]
+    DimensionBelowFloor,
+    #[msg("Proposal submissions are paused")]
+    SubmissionsPaused,
+    #[msg("Arithmetic overflow")]
+    ArithmeticOverflow,
+    #[msg("Insufficient voting credits")]
+    InsufficientCredits,
+    #[msg("Max execution attempts must be at least 1")]
+    InvalidExecutionAttempts,
+    #[msg("Ratified action slot is in the future")]
+    InvalidRatificationSlot,
+    #[msg("Proposal is not a ratification")]
+    NotRatification,
+    #[msg("Ratification proposals cannot be executed")]
+    RatificationNotExecutable,
+    #[msg("Proposal must offer at least two options")]
+    InvalidOptionCount,
+    #[msg("Too many proposal options")]
+    TooManyOptions,
+    #[msg("Notification preferences set unused bits")]
+    InvalidNotificationPrefs,
+    #[msg("Proposal is not awaiting an EPI override")]
+    NotPendingEpiOverride,
+    #[msg("Proposal is not in a terminal state")]
+    ProposalNotTerminal,
+    #[msg("Slot arithmetic overflowed")]
+    SlotOverflow,
+    #[msg("Voting period exceeds the maximum")]
+    InvalidVotingPeriod,
+    #[msg("Invalid veto delegate")]
+    InvalidVetoDelegate,
+    #[msg("Veto delegation has expired")]
+    VetoDelegationExpired,
+    #[msg("Proposal already has votes")]
+    ProposalHasVotes,
+    #[msg("Invalid execution condition operator")]
+    InvalidConditionOperator,
+    #[msg("Oracle account missing or does not match the execution condition")]
+    InvalidOracleAccount,
+    #[msg("Execution condition not met")]
+    ExecutionConditionNotMet,
+    #[msg("Sortition tie-break is disabled")]
+    SortitionDisabled,
+    #[msg("Proposal is not tied")]
+    NotTied,
+    #[msg("Tie has already been resolved")]
+    TieAlreadyResolved,
+    #[msg("Invalid SlotHashes sysvar data")]
+    InvalidSlotHashes,
+    #[msg("Execution target is not on the allowlist")]
+    ExecutionTargetNotAllowed,
+    #[msg("Execution target is already on the allowlist")]
+    ExecutionTargetAlreadyAllowed,
+    #[msg("Execution target allowlist is full")]
+    ExecutionAllowlistFull,
+    #[msg("Winning margin is below the required minimum")]
+    MarginTooNarrow,
+    #[msg("Quorum can still be reached")]
+    QuorumStillReachable,
+    #[msg("Proposal document schema version is too old")]
+    DocumentSchemaTooOld,
+    #[msg("Invalid proposal category")]
+    InvalidCategory,
+    #[msg("Too many active proposals in this category")]
+    CategoryActiveLimitReached,
+    #[msg("Batch too large")]
+    BatchTooLarge,
+    #[msg("Summary too long (max 128 chars)")]
+    SummaryTooLong,
+    #[msg("Proposal has reached its deliberation limit")]
+    TooManyDeliberations,
+    #[msg("Veto threshold must be between 1 and the guardian count")]
+    InvalidVetoThreshold,
+    #[msg("Governance is paused")]
+    GovernancePaused,
+    #[msg("New vote matches the existing vote")]
+    VoteUnchanged,
+    #[msg("Proposal has not succeeded")]
+    ProposalNotSucceeded,
+    #[msg("Tied proposal must be resolved by sortition first")]
+    TieNotResolved,
+    #[msg("Cannot delegate to yourself")]
+    SelfDelegation,
+    #[msg("Voting power is already delegated")]
+    AlreadyDelegated,
+    #[msg("Delegate has delegated to you")]
+    DelegationCycle,
+    #[msg("Voting power is not delegated to this account")]
+    NotDelegated,
+    #[msg("Voting power is delegated; undelegate to vote directly")]
+    VotingPowerDelegated,
+    #[msg("Voters delegating to this account must be passed for clearing")]
+    DelegatorsRemaining,
+    #[msg("Execution timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Vote reason too long")]
+    ReasonTooLong,
+    #[msg("Unknown voting mode")]
+    InvalidVotingMode,
+    #[msg("No unsettled deposit on this proposal")]
+    NoDepositToClaim,
+    #[msg("Profit and ethics weights must sum to 10000")]
+    InvalidEpiWeights,
+    #[msg("Proposal is not vetoed")]
+    ProposalNotVetoed,
+    #[msg("Veto has already been overridden once")]
+    VetoAlreadyOverridden,
+    #[msg("Veto overrides are disabled")]
+    VetoOverrideDisabled,
+    #[msg("Support does not exceed the override threshold")]
+    OverrideThresholdNotMet,
+    #[msg("Override threshold must be at most 10000 basis points")]
+    InvalidOverrideThreshold,
+    #[msg("Voter account does not match the expected PDA")]
+    InvalidVoterAccount,
+    #[msg("Voter is already registered")]
+    VoterAlreadyRegistered,
+    #[msg("Instruction data exceeds the maximum payload length")]
+    InstructionDataTooLong,
+    #[msg("Instruction data requires an execution target")]
+    MissingExecutionTarget,
+    #[msg("Proposal is frozen by a guardian")]
+    ProposalFrozen,
+    #[msg("Proposal is not frozen")]
+    ProposalNotFrozen,
+    #[msg("Voting start delay exceeds the configured maximum")]
+    StartDelayTooLong,
+    #[msg("Guardian cap reached")]
+    GuardianCapReached,
+    #[msg("Guardian cap must be positive and at least the current guardian count")]
+    InvalidGuardianCap,
+    #[msg("Proposal can only be amended before voting starts")]
+    CannotAmend,
+    #[msg("Proposer must wait for the proposal cooldown to elapse")]
+    ProposalCooldownActive,
+    #[msg("Proposer does not hold the token required to submit proposals")]
+    NotEligibleToPropose,
+    #[msg("Proposal is tied and ties do not pass")]
+    ProposalTied,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Voting power is below the minimum required to vote")]
+    InsufficientVotingPower,
+    #[msg("The veto window for this proposal has closed")]
+    VetoWindowClosed,
+    #[msg("Thought hash is already used by another proposal")]
+    DuplicateThoughtHash,
+    #[msg("Guardian veto weight must be positive")]
+    InvalidVetoWeight,
+    #[msg("An earlier succeeded proposal must be executed or settled first")]
+    OutOfOrderExecution,
+    #[msg("Proposal is still awaiting execution")]
+    ProposalStillQueued,
+    #[msg("Text length limit must be non-zero and within the reserved account space")]
+    InvalidTextLimit,
+    #[msg("Appeal id does not match the appeal account")]
+    InvalidAppeal,
+    #[msg("Appeal has already been resolved")]
+    AppealAlreadyResolved,
+    #[msg("Proposal is not in a terminal state")]
+    ProposalStillActive,
+    #[msg("Proposal deposit has not been settled")]
+    DepositNotSettled,
+    #[msg("Cleanup delay has not elapsed")]
+    CleanupDelayNotElapsed,
+    #[msg("Quorum must be at most 10000 basis points")]
+    InvalidQuorum,
+    #[msg("Guardian term has expired")]
+    GuardianTermExpired,
+    #[msg("Too few distinct voters took part")]
+    TurnoutNotReached,
+    #[msg("EPI score meets the threshold; nothing to record")]
+    EPIMeetsThreshold,
+    #[msg("Voting power is locked until voting ends on proposals this voter voted on")]
+    VotingPowerLocked,
+    #[msg("Guardian veto cooldown has not elapsed")]
+    VetoCooldownActive,
+    #[msg("Treasury balance cannot cover the disbursement")]
+    InsufficientTreasury,
+    #[msg("Disbursement needs a recipient and a non-zero amount")]
+    InvalidDisbursement,
+    #[msg("Recipient account does not match the proposal's recipient")]
+    InvalidRecipient,
+    #[msg("Not enough authority council members approved this instruction")]
+    AuthorityQuorumNotMet,
+    #[msg("Invalid authority council member")]
+    InvalidAuthoritySigner,
+    #[msg("Authority council has no free seat")]
+    AuthorityCouncilFull,
+    #[msg("Authority threshold exceeds the council size")]
+    InvalidAuthorityThreshold,
+    #[msg("Emergency proposals are disabled")]
+    EmergencyProposalsDisabled,
+    #[msg("Vetoed proposal can still be overridden")]
+    VetoStillOverridable,
+    #[msg("Delegated power cannot be delegated again")]
+    DelegationChain,
+    #[msg("Ranked-choice proposals take ranked ballots or abstentions")]
+    RankedBallotRequired,
+    #[msg("Proposal is not ranked-choice")]
+    NotRankedChoice,
+    #[msg("Rankings must list distinct options of the proposal")]
+    InvalidRankings,
+    #[msg("Vote record does not belong to this proposal")]
+    InvalidVoteRecord,
+    #[msg("Ranked-choice count is not complete")]
+    RankedTallyIncomplete,
+    #[msg("Ranked-choice count is already complete")]
+    RankedTallyComplete,
+    #[msg("Votes cannot be changed or withdrawn while early execution is enabled")]
+    VotesFinal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Account state as freshly allocated: every field zero, empty or `None`
+    fn zeroed<T: AnchorDeserialize>() -> T {
+        let bytes = vec![0u8; 8192];
+        T::deserialize(&mut &bytes[..]).unwrap()
+    }
+
+    fn governance() -> Governance {
+        let mut governance: Governance = zeroed();
+        governance.quorum_bps = 5100;
+        governance.profit_weight = 5000;
+        governance.ethics_weight = 5000;
+        governance.epi_threshold = 700_000;
+        governance
+    }
+
+    fn proposal() -> Proposal {
+        let mut proposal: Proposal = zeroed();
+        proposal.status = ProposalStatus::Active;
+        proposal.num_options = 2;
+        proposal.total_power_snapshot = 1_000;
+        proposal
+    }
+
+    fn error_code(result: Result<()>) -> u32 {
+        match result {
+            Err(Error::AnchorError(error)) => error.error_code_number,
+            other => panic!("expected an anchor error, got {:?}", other),
+        }
+    }
+
+    fn code(error: GovernanceError) -> u32 {
+        error as u32 + anchor_lang::error::ERROR_CODE_OFFSET
+    }
+
+    #[test]
+    fn scores_correctable_before_any_vote() {
+        assert!(require_correctable(&proposal()).is_ok());
+    }
+
+    #[test]
+    fn scores_not_correctable_after_a_vote() {
+        let mut proposal = proposal();
+        proposal.votes_abstain = 1;
+        assert_eq!(
+            error_code(require_correctable(&proposal)),
+            code(GovernanceError::ProposalHasVotes)
+        );
+    }
+
+    #[test]
+    fn corrected_scores_must_clear_threshold() {
+        let governance = governance();
+        let passing = evaluate_epi(&governance, 800_000, 800_000, &[], 700_000).unwrap();
+        assert!(passing.meets_threshold);
+        let failing = evaluate_epi(&governance, 600_000, 600_000, &[], 700_000).unwrap();
+        assert!(!failing.meets_threshold);
+    }
+
+    #[test]
+    fn corrected_category_must_exist_and_have_room() {
+        let mut governance = governance();
+        assert!(require_category_capacity(&governance, 0).is_ok());
+        assert_eq!(
+            error_code(require_category_capacity(&governance, MAX_CATEGORIES as u8)),
+            code(GovernanceError::InvalidCategory)
+        );
+        governance.max_active_per_category = 2;
+        governance.category_active_counts[3] = 2;
+        assert_eq!(
+            error_code(require_category_capacity(&governance, 3)),
+            code(GovernanceError::CategoryActiveLimitReached)
+        );
+    }
+
+    fn ranked_ballot(proposal: &mut Proposal, rankings: &[u8], weight: u64) -> VoteRecord {
+        let mut ballot: VoteRecord = zeroed();
+        ballot.proposal_id = proposal.id;
+        ballot.support = rankings[0];
+        ballot.counted_option = rankings[0];
+        ballot.rankings = rankings.to_vec();
+        ballot.voting_power = weight;
+        add_to_tally(proposal, rankings[0], weight).unwrap();
+        ballot
+    }
+
+    #[test]
+    fn rankings_must_be_distinct_options() {
+        let mut proposal = proposal();
+        proposal.num_options = 4;
+        assert!(require_valid_rankings(&proposal, &[2, 0, 3]).is_ok());
+        for rankings in [&[][..], &[4], &[1, 1]] {
+            assert_eq!(
+                error_code(require_valid_rankings(&proposal, rankings)),
+                code(GovernanceError::InvalidRankings)
+            );
+        }
+    }
+
+    #[test]
+    fn ranked_count_with_max_options_completes_across_calls() {
+        let mut proposal = proposal();
+        proposal.num_options = MAX_PROPOSAL_OPTIONS;
+        proposal.option_votes = vec![0; MAX_PROPOSAL_OPTIONS as usize];
+        proposal.is_ranked = true;
+        let mut ballots = [
+            ranked_ballot(&mut proposal, &[0], 30),
+            ranked_ballot(&mut proposal, &[1], 25),
+            ranked_ballot(&mut proposal, &[2], 20),
+            ranked_ballot(&mut proposal, &[3, 2], 10),
+            ranked_ballot(&mut proposal, &[4, 1], 6),
+            ranked_ballot(&mut proposal, &[5, 2, 1], 5),
+            ranked_ballot(&mut proposal, &[6], 3),
+            ranked_ballot(&mut proposal, &[7, 6, 5], 1),
+        ];
+
+        // The only ballot for option 7 is left out, so round 1 stays open
+        let mut first_call: Vec<&mut VoteRecord> = ballots.iter_mut().take(7).collect();
+        advance_instant_runoff(&mut proposal, &mut first_call, 0).unwrap();
+        assert_eq!(proposal.current_round, 1);
+        assert_eq!(proposal.eliminating_option, Some(7));
+        assert!(!proposal.ranked_tally_complete);
+        let outcome = apply_outcome(&mut proposal, &mut governance(), &Clock::default());
+        assert_eq!(
+            error_code(outcome.map(|_| ())),
+            code(GovernanceError::RankedTallyIncomplete)
+        );
+
+        let mut second_call: Vec<&mut VoteRecord> = ballots.iter_mut().collect();
+        advance_instant_runoff(&mut proposal, &mut second_call, 0).unwrap();
+        assert!(proposal.ranked_tally_complete);
+        assert_eq!(proposal.current_round, 6);
+        assert_eq!(proposal.eliminating_option, None);
+        assert_eq!(proposal.leading_options(), vec![2]);
+        assert_eq!(proposal.option_tally(1), 31);
+        assert_eq!(proposal.option_tally(2), 35);
+        assert_eq!(proposal.exhausted_votes, 34);
+        assert_eq!(proposal.option_turnout(), 100);
+        assert_eq!(ballots[7].counted_option, EXHAUSTED_BALLOT);
+        assert_eq!(ballots[5].counted_option, 2);
+    }
+
+    /// A binary proposal on 1_000 units of live power, `min_voters` and
+    /// `min_margin` zero, with 100 votes against
+    fn early_decision(votes_for: u64) -> (Proposal, Governance) {
+        let mut governance = governance();
+        governance.total_voting_power = 1_000;
+        let mut proposal = proposal();
+        proposal.votes_against = 100;
+        proposal.votes_for = votes_for;
+        (proposal, governance)
+    }
+
+    #[test]
+    fn outcome_decided_only_past_every_unvoted_unit() {
+        // 500 for against 100 + 400 unvoted could still tie
+        let (proposal, governance) = early_decision(500);
+        assert!(!outcome_decided(&proposal, &governance).unwrap());
+        let (proposal, governance) = early_decision(501);
+        assert!(outcome_decided(&proposal, &governance).unwrap());
+    }
+
+    #[test]
+    fn outcome_decided_counts_power_registered_after_the_snapshot() {
+        let (proposal, mut governance) = early_decision(501);
+        governance.total_voting_power = 1_100;
+        assert!(!outcome_decided(&proposal, &governance).unwrap());
+        let (proposal, mut governance) = early_decision(551);
+        governance.total_voting_power = 1_100;
+        assert!(outcome_decided(&proposal, &governance).unwrap());
+    }
+
+    #[test]
+    fn outcome_decided_counts_credits_that_could_still_vote() {
+        let (proposal, mut governance) = early_decision(501);
+        governance.total_credit_budget = 2;
+        assert!(!outcome_decided(&proposal, &governance).unwrap());
+
+        // Bought votes did not come out of registered power, which stays unvoted
+        let (mut proposal, governance) = early_decision(501);
+        proposal.credit_votes = 2;
+        assert!(!outcome_decided(&proposal, &governance).unwrap());
+    }
+
+    #[test]
+    fn quorum_bps_5100_requires_51_percent() {
+        let governance = governance();
+        let mut proposal = proposal();
+        assert_eq!(quorum_votes_of(&governance, &proposal, 1_000).unwrap(), 510);
+        // Widened before multiplying, so the largest electorate cannot overflow
+        let largest = quorum_votes_of(&governance, &proposal, u64::MAX).unwrap();
+        assert_eq!(largest as u128, u64::MAX as u128 * 5100 / 10000);
+
+        proposal.votes_for = 509;
+        assert!(!quorum_reached(&proposal, &governance).unwrap());
+        proposal.votes_for = 510;
+        assert!(quorum_reached(&proposal, &governance).unwrap());
+    }
+
+    #[test]
+    fn quorum_bps_follows_category_and_emergency_overrides() {
+        let mut governance = governance();
+        governance.category_quorums[1] = 2000;
+        governance.emergency_quorum = 7500;
+        let mut proposal = proposal();
+        proposal.category = 1;
+        assert_eq!(quorum_votes(&proposal, &governance).unwrap(), 200);
+        proposal.is_emergency = true;
+        assert_eq!(quorum_votes(&proposal, &governance).unwrap(), 750);
+    }
+
+    fn tied_proposal() -> Proposal {
+        let mut proposal = proposal();
+        proposal.votes_for = 300;
+        proposal.votes_against = 300;
+        proposal
+    }
+
+    #[test]
+    fn tie_passes_when_ties_break_for() {
+        let mut governance = governance();
+        governance.tie_breaks_for = true;
+        let proposal = tied_proposal();
+        assert!(evaluate_outcome(&proposal, &governance).unwrap().is_none());
+        assert_eq!(winning_option(&proposal, &governance), Some(1));
+    }
+
+    #[test]
+    fn tie_fails_without_a_tiebreak() {
+        let governance = governance();
+        let proposal = tied_proposal();
+        assert!(matches!(
+            evaluate_outcome(&proposal, &governance).unwrap(),
+            Some(DefeatReason::Tied)
+        ));
+        assert_eq!(winning_option(&proposal, &governance), None);
+    }
+
+    #[test]
+    fn isqrt_rounds_down_at_every_boundary() {
+        assert_eq!(isqrt(0), 0);
+        assert_eq!(isqrt(1), 1);
+        for root in [2u64, 3, 10, 1_000, 65_535, 4_294_967_295] {
+            assert_eq!(isqrt(root * root), root);
+            assert_eq!(isqrt(root * root - 1), root - 1);
+            assert_eq!(isqrt(root * root + 1), root);
+        }
+        assert_eq!(isqrt(u64::MAX), 4_294_967_295);
+    }
+
+    #[test]
+    fn quadratic_mode_weighs_by_square_root() {
+        let mut governance = governance();
+        assert_eq!(governance.vote_weight(10_000), 10_000);
+        governance.voting_mode = VOTING_MODE_QUADRATIC;
+        assert_eq!(governance.vote_weight(10_000), 100);
+        assert_eq!(governance.vote_weight(99), 9);
+    }
+
+    fn leaf(byte: u8) -> [u8; 32] {
+        [byte; 32]
+    }
+
+    fn pair_hash(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (lo, hi) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[lo, hi]).to_bytes()
+    }
+
+    #[test]
+    fn merkle_root_of_empty_and_single_leaf_sets() {
+        assert_eq!(compute_merkle_root(&[]), [0u8; 32]);
+        assert_eq!(compute_merkle_root(&[leaf(7)]), leaf(7));
+    }
+
+    #[test]
+    fn merkle_root_hashes_sorted_pairs_and_promotes_odd_nodes() {
+        let (a, b, c) = (leaf(1), leaf(2), leaf(3));
+        assert_eq!(compute_merkle_root(&[a, b]), pair_hash(&a, &b));
+        assert_eq!(compute_merkle_root(&[b, a]), compute_merkle_root(&[a, b]));
+        assert_eq!(
+            compute_merkle_root(&[a, b, c]),
+            pair_hash(&pair_hash(&a, &b), &c)
+        );
+        assert_ne!(
+            compute_merkle_root(&[a, b, c]),
+            compute_merkle_root(&[a, b])
+        );
+    }
+
+    #[test]
+    fn veto_weight_scales_with_support() {
+        let mut landslide = proposal();
+        landslide.votes_for = 900;
+        landslide.votes_against = 100;
+        assert_eq!(required_veto_weight(&landslide, 10), 9);
+
+        let mut narrow = proposal();
+        narrow.votes_for = 510;
+        narrow.votes_against = 490;
+        assert_eq!(required_veto_weight(&narrow, 10), 6);
+    }
+
+    #[test]
+    fn veto_weight_rounds_up_and_never_drops_below_one() {
+        assert_eq!(required_veto_weight(&proposal(), 10), 1);
+
+        let mut proposal = proposal();
+        proposal.votes_for = 1;
+        proposal.votes_against = 999;
+        assert_eq!(required_veto_weight(&proposal, 10), 1);
+        proposal.votes_for = 101;
+        proposal.votes_against = 899;
+        assert_eq!(required_veto_weight(&proposal, 10), 2);
+    }
+
+    #[test]
+    fn absurd_voting_period_overflows_instead_of_wrapping() {
+        assert_eq!(
+            voting_end_slot(100, MAX_VOTING_PERIOD).unwrap(),
+            100 + MAX_VOTING_PERIOD
+        );
+        assert_eq!(
+            error_code(voting_end_slot(100, u64::MAX).map(|_| ())),
+            code(GovernanceError::SlotOverflow)
+        );
+    }
+
+    #[test]
+    fn deputy_vetoes_only_until_delegation_expires() {
+        let mut guardian: GuardianAccount = zeroed();
+        guardian.guardian = Pubkey::new_unique();
+        let deputy = Pubkey::new_unique();
+        guardian.veto_delegate = Some(VetoDelegation {
+            deputy,
+            expires_at_slot: 50,
+        });
+
+        assert!(authorize_veto_signer(&guardian, &guardian.guardian, 1_000).is_ok());
+        assert!(authorize_veto_signer(&guardian, &deputy, 50).is_ok());
+        assert_eq!(
+            error_code(authorize_veto_signer(&guardian, &deputy, 51)),
+            code(GovernanceError::VetoDelegationExpired)
+        );
+        assert_eq!(
+            error_code(authorize_veto_signer(&guardian, &Pubkey::new_unique(), 10)),
+            code(GovernanceError::Unauthorized)
+        );
+    }
+
+    #[test]
+    fn veto_score_decays_per_window_while_veto_count_stays() {
+        let mut governance = governance();
+        governance.veto_decay_window = 100;
+        governance.veto_decay_per_window = 2;
+        let mut guardian: GuardianAccount = zeroed();
+        guardian.veto_count = 5;
+        guardian.recent_veto_score = 5;
+
+        assert_eq!(guardian.decayed_veto_score(&governance, 99), (5, 0));
+        // A partial window's progress is kept for the next update
+        guardian.apply_veto_decay(&governance, 250);
+        assert_eq!(guardian.recent_veto_score, 1);
+        assert_eq!(guardian.veto_score_updated_slot, 200);
+        guardian.apply_veto_decay(&governance, 300);
+        assert_eq!(guardian.recent_veto_score, 0);
+        assert_eq!(guardian.veto_count, 5);
+    }
+
+    #[test]
+    fn execution_condition_reads_the_oracle_value() {
+        let mut data = vec![0u8; 16];
+        data[8..].copy_from_slice(&42i64.to_le_bytes());
+        let condition = |operator, threshold| ExecutionCondition {
+            oracle: Pubkey::new_unique(),
+            value_offset: 8,
+            operator,
+            threshold,
+        };
+
+        assert!(condition(CONDITION_GTE, 42).evaluate(&data).unwrap());
+        assert!(!condition(CONDITION_GT, 42).evaluate(&data).unwrap());
+        assert!(condition(CONDITION_EQ, 42).evaluate(&data).unwrap());
+        assert!(!condition(CONDITION_LT, 0).evaluate(&data).unwrap());
+        assert_eq!(
+            error_code(condition(CONDITION_GT, 0).evaluate(&data[..15]).map(|_| ())),
+            code(GovernanceError::InvalidOracleAccount)
+        );
+    }
+
+    #[test]
+    fn end_slot_is_the_last_voting_slot() {
+        let mut proposal = proposal();
+        proposal.end_slot = 100;
+        assert!(is_voting_open(&proposal, 100));
+        assert!(require_votable(&proposal, 0, 100).is_ok());
+        assert!(!is_voting_open(&proposal, 101));
+        assert_eq!(
+            error_code(require_votable(&proposal, 0, 101)),
+            code(GovernanceError::VotingEnded)
+        );
+    }
+
+    #[test]
+    fn sortition_picks_each_tied_option_by_seed() {
+        let tied = [1u8, 4, 6];
+        let seed = |word: u64| {
+            let mut seed = [0xffu8; 32];
+            seed[..8].copy_from_slice(&word.to_le_bytes());
+            seed
+        };
+        assert_eq!(select_tied_option(&tied, &seed(0)), 1);
+        assert_eq!(select_tied_option(&tied, &seed(1)), 4);
+        assert_eq!(select_tied_option(&tied, &seed(2)), 6);
+        assert_eq!(select_tied_option(&tied, &seed(3)), 1);
+
+        // Over many seeds every tied option is drawn about equally often
+        let mut draws = [0u32; 8];
+        for word in 0..3_000u64 {
+            let seed = hashv(&[&word.to_le_bytes()]).to_bytes();
+            draws[select_tied_option(&tied, &seed) as usize] += 1;
+        }
+        for option in tied {
+            assert!((900..1_100).contains(&draws[option as usize]));
+        }
+    }
+
+    #[test]
+    fn voter_stats_follow_a_changed_vote() {
+        let mut voter: VoterAccount = zeroed();
+        voter.record_vote(1, 10);
+        assert_eq!((voter.total_votes_cast, voter.proposals_voted_for), (1, 1));
+
+        voter.change_vote(1, 0, 20);
+        assert_eq!(voter.total_votes_cast, 1);
+        assert_eq!(
+            (voter.proposals_voted_for, voter.proposals_voted_against),
+            (0, 1)
+        );
+        assert_eq!(voter.last_vote_slot, 20);
+
+        voter.withdraw_vote(0, 30);
+        assert_eq!(
+            (voter.total_votes_cast, voter.proposals_voted_against),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn execution_allowlist_is_opt_in() {
+        let mut governance = governance();
+        let allowed = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        assert!(governance.is_execution_target_allowed(&other));
+
+        governance.execution_target_allowlist = vec![allowed];
+        assert!(governance.is_execution_target_allowed(&allowed));
+        assert!(!governance.is_execution_target_allowed(&other));
+    }
+
+    #[test]
+    fn min_margin_defeats_a_narrow_majority() {
+        let mut governance = governance();
+        governance.min_margin = 50;
+        let mut proposal = proposal();
+        proposal.votes_for = 320;
+        proposal.votes_against = 280;
+        assert!(matches!(
+            evaluate_outcome(&proposal, &governance).unwrap(),
+            Some(DefeatReason::MarginTooNarrow)
+        ));
+
+        proposal.votes_for = 330;
+        assert!(evaluate_outcome(&proposal, &governance).unwrap().is_none());
+    }
+
+    #[test]
+    fn full_category_leaves_others_open() {
+        let mut governance = governance();
+        governance.max_active_per_category = 2;
+        governance.category_active_counts[1] = 2;
+        assert_eq!(
+            error_code(require_category_capacity(&governance, 1)),
+            code(GovernanceError::CategoryActiveLimitReached)
+        );
+        assert!(require_category_capacity(&governance, 2).is_ok());
+
+        let mut proposal = proposal();
+        proposal.category = 1;
+        governance.release_active_slot(&proposal);
+        assert!(require_category_capacity(&governance, 1).is_ok());
+    }
+
+    #[test]
+    fn stale_snapshot_requorums_against_live_power() {
+        let governance = governance();
+        let mut proposal = proposal();
+        proposal.votes_for = 600;
+        assert!(quorum_reached(&proposal, &governance).unwrap());
+        // Grown electorate: 51% of 1_500 is 765
+        assert!(!quorum_reached_with(&proposal, &governance, 1_500).unwrap());
+        // Shrunk electorate: 51% of 800 is 408
+        assert!(quorum_reached_with(&proposal, &governance, 800).unwrap());
+    }
+
+    #[test]
+    fn category_execution_authority_overrides_the_global_one() {
+        let mut governance = governance();
+        assert_eq!(governance.execution_authority_for(0), None);
+
+        let global = Pubkey::new_unique();
+        let treasury_executor = Pubkey::new_unique();
+        governance.execution_authority = Some(global);
+        governance.category_execution_authorities[2] = Some(treasury_executor);
+        assert_eq!(governance.execution_authority_for(0), Some(global));
+        assert_eq!(
+            governance.execution_authority_for(2),
+            Some(treasury_executor)
+        );
+    }
+
+    #[test]
+    fn phase_follows_the_proposal_lifecycle() {
+        let mut governance = governance();
+        governance.veto_window = 50;
+        governance.execution_delay = 100;
+        let mut proposal = proposal();
+        proposal.start_slot = 100;
+        proposal.end_slot = 200;
+
+        let phase = |proposal: &Proposal, slot| derive_phase(proposal, &governance, slot);
+        assert!(matches!(
+            phase(&proposal, 50),
+            (ProposalPhase::Draft, Some(50))
+        ));
+        assert!(matches!(
+            phase(&proposal, 200),
+            (ProposalPhase::Voting, Some(1))
+        ));
+        assert!(matches!(
+            phase(&proposal, 201),
+            (ProposalPhase::VotingEnded, None)
+        ));
+
+        proposal.status = ProposalStatus::Succeeded;
+        proposal.queued_at_slot = 201;
+        assert!(matches!(
+            phase(&proposal, 220),
+            (ProposalPhase::VetoWindow, Some(31))
+        ));
+        assert!(matches!(
+            phase(&proposal, 260),
+            (ProposalPhase::InTimelock, Some(41))
+        ));
+        assert!(matches!(
+            phase(&proposal, 301),
+            (ProposalPhase::Executable, None)
+        ));
+
+        proposal.status = ProposalStatus::Executed;
+        assert!(matches!(
+            phase(&proposal, 301),
+            (ProposalPhase::Executed, None)
+        ));
+    }
+
+    #[test]
+    fn abstentions_count_toward_quorum_only_when_configured() {
+        let mut governance = governance();
+        let mut proposal = proposal();
+        proposal.votes_for = 300;
+        proposal.votes_abstain = 300;
+        assert_eq!(quorum_turnout(&proposal, &governance).unwrap(), 300);
+        assert!(!quorum_reached(&proposal, &governance).unwrap());
+
+        governance.abstain_counts_for_quorum = true;
+        assert_eq!(quorum_turnout(&proposal, &governance).unwrap(), 600);
+        assert!(quorum_reached(&proposal, &governance).unwrap());
+    }
+
+    #[test]
+    fn quorum_of_a_huge_electorate_does_not_overflow() {
+        let mut governance = governance();
+        governance.quorum_bps = 5000;
+        let total = u64::MAX / 2;
+        assert_eq!(
+            quorum_votes_of(&governance, &proposal(), total).unwrap(),
+            total / 2
+        );
+    }
+
+    #[test]
+    fn vote_hash_chain_commits_to_every_event_in_order() {
+        let mut record: VoteRecord = zeroed();
+        record.voter = Pubkey::new_unique();
+        record.support = 1;
+        record.voting_power = 40;
+        assert_ne!(record.leaf(), record.withdrawal_leaf());
+
+        let mut cast_then_withdrawn = proposal();
+        assert_eq!(cast_then_withdrawn.vote_hash_chain, [0u8; 32]);
+        cast_then_withdrawn.chain_vote(&record.leaf());
+        assert_eq!(
+            cast_then_withdrawn.vote_hash_chain,
+            hashv(&[&[0u8; 32], &record.leaf()]).to_bytes()
+        );
+        cast_then_withdrawn.chain_vote(&record.withdrawal_leaf());
+
+        let mut reordered = proposal();
+        reordered.chain_vote(&record.withdrawal_leaf());
+        reordered.chain_vote(&record.leaf());
+        assert_ne!(
+            cast_then_withdrawn.vote_hash_chain,
+            reordered.vote_hash_chain
+        );
+    }
+
+    #[test]
+    fn derived_addresses_match_the_account_seeds() {
+        let id = 7u64;
+        let voter = Pubkey::new_unique();
+        let (proposal, _) =
+            Pubkey::find_program_address(&[b"proposal", id.to_le_bytes().as_ref()], &crate::ID);
+        let (vote_record, _) = Pubkey::find_program_address(
+            &[b"vote", id.to_le_bytes().as_ref(), voter.as_ref()],
+            &crate::ID,
+        );
+        assert_eq!(Proposal::address(id), proposal);
+        assert_eq!(VoteRecord::address(id, &voter), vote_record);
+    }
+
+    #[test]
+    fn notification_prefs_round_trip() {
+        let mut voter: VoterAccount = zeroed();
+        voter.notification_prefs = NOTIFY_NEW_PROPOSAL | NOTIFY_EXECUTION;
+        let mut data = Vec::new();
+        voter.try_serialize(&mut data).unwrap();
+        let stored = VoterAccount::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(
+            stored.notification_prefs,
+            NOTIFY_NEW_PROPOSAL | NOTIFY_EXECUTION
+        );
+        assert_eq!(NOTIFY_ALL, 0b111);
+    }
+
+    #[test]
+    fn removed_guardian_is_not_serving() {
+        let mut guardian: GuardianAccount = zeroed();
+        guardian.is_active = true;
+        guardian.term_end = 100;
+        assert!(guardian.require_serving(100).is_ok());
+        assert_eq!(
+            error_code(guardian.require_serving(101)),
+            code(GovernanceError::GuardianTermExpired)
+        );
+
+        guardian.is_active = false;
+        assert_eq!(
+            error_code(guardian.require_serving(50)),
+            code(GovernanceError::NotGuardian)
+        );
+    }
 }